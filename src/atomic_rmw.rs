@@ -0,0 +1,95 @@
+use super::*;
+
+macro_rules! impl_atomic_rmw {
+  ($($name:ident => $arm:literal, $riscv:literal, $desc:literal;)*) => {
+    impl<A> VolAddress<u32, Safe, Safe, A> {
+      $(
+        #[doc = concat!("Atomically ", $desc, ", returning the old value.")]
+        ///
+        /// On ARM this is an `LDREX`/`STREX` loop, and on RISC-V this is an
+        /// `LR`/`SC` loop, so an interrupt between the read and the write will
+        /// cause the operation to retry rather than lose an update. On other
+        /// targets this uses the matching method of `AtomicU32`.
+        ///
+        /// The operation is sequentially consistent.
+        ///
+        /// ## Safety
+        /// * The address must be normal memory (eg: RAM shared with an
+        ///   interrupt handler) which supports exclusive access on your
+        ///   target. Exclusive access to MMIO registers is generally not
+        ///   supported.
+        /// * On ARM, the target must be ARMv7 or later (including ARMv7-M and
+        ///   ARMv8-M), since the `DMB` barrier this uses isn't available on
+        ///   earlier versions. On RISC-V, the target must have the "A" extension.
+        #[inline]
+        pub unsafe fn $name(self, val: u32) -> u32 {
+          let addr = self.as_usize();
+          #[cfg(target_arch = "arm")]
+          {
+            let old: u32;
+            core::arch::asm!(
+              "dmb",
+              "2:",
+              "ldrex {old}, [{addr}]",
+              concat!($arm, " {new}, {old}, {val}"),
+              "strex {fail}, {new}, [{addr}]",
+              "cmp {fail}, #0",
+              "bne 2b",
+              "dmb",
+              addr = in(reg) addr,
+              val = in(reg) val,
+              old = out(reg) old,
+              new = out(reg) _,
+              fail = out(reg) _,
+              options(nostack),
+            );
+            old
+          }
+          #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+          {
+            let old: u32;
+            core::arch::asm!(
+              "2:",
+              "lr.w.aqrl {old}, ({addr})",
+              concat!($riscv, " {new}, {old}, {val}"),
+              "sc.w.aqrl {fail}, {new}, ({addr})",
+              "bnez {fail}, 2b",
+              addr = in(reg) addr,
+              val = in(reg) val,
+              old = out(reg) old,
+              new = out(reg) _,
+              fail = out(reg) _,
+              options(nostack),
+            );
+            old
+          }
+          #[cfg(not(any(
+            target_arch = "arm",
+            target_arch = "riscv32",
+            target_arch = "riscv64"
+          )))]
+          {
+            (*(addr as *const core::sync::atomic::AtomicU32))
+              .$name(val, core::sync::atomic::Ordering::SeqCst)
+          }
+        }
+      )*
+    }
+  };
+}
+impl_atomic_rmw! {
+  fetch_or => "orr", "or", "bitwise ORs `val` into the value";
+  fetch_and => "and", "and", "bitwise ANDs `val` into the value";
+  fetch_add => "add", "add", "adds `val` to the value (wrapping on overflow)";
+}
+
+#[test]
+fn test_atomic_rmw() {
+  let mut hw = 0b0110_u32;
+  let a: VolAddress<u32, Safe, Safe> =
+    unsafe { VolAddress::new(&mut hw as *mut u32 as usize) };
+  assert_eq!(unsafe { a.fetch_or(0b0001) }, 0b0110);
+  assert_eq!(unsafe { a.fetch_and(0b0011) }, 0b0111);
+  assert_eq!(unsafe { a.fetch_add(1) }, 0b0011);
+  assert_eq!(a.read(), 0b0100);
+}
@@ -0,0 +1,65 @@
+use super::*;
+use core::sync::atomic::{
+  AtomicU16, AtomicU32, AtomicU8, AtomicUsize, Ordering,
+};
+
+macro_rules! impl_atomics {
+  ($($t:ty => $atomic:ty),*) => {
+    $(
+      impl<A> VolAddress<$t, Safe, Safe, A> {
+        /// Views the address as an atomic value.
+        ///
+        /// This lets you use the full set of atomic operations on the value.
+        ///
+        /// ## Safety
+        /// * The address must be normal memory (eg: RAM shared with an
+        ///   interrupt handler or with DMA) which supports atomic access on
+        ///   your target. Atomic access to MMIO registers is generally not
+        ///   supported.
+        /// * All other access to the address must be atomic or volatile for
+        ///   as long as the reference is used.
+        #[inline]
+        #[must_use]
+        pub unsafe fn as_atomic(self) -> &'static $atomic {
+          &*(self.as_usize() as *const $atomic)
+        }
+
+        /// Atomically swaps in the new value, returning the old value.
+        ///
+        /// ## Safety
+        /// * The address must be normal memory (eg: RAM shared with an
+        ///   interrupt handler or with DMA) which supports atomic access on
+        ///   your target. Atomic access to MMIO registers is generally not
+        ///   supported.
+        #[inline]
+        pub unsafe fn exchange_atomic(self, new: $t) -> $t {
+          self.as_atomic().swap(new, Ordering::SeqCst)
+        }
+      }
+    )*
+  };
+}
+impl_atomics!(
+  u8 => AtomicU8,
+  u16 => AtomicU16,
+  u32 => AtomicU32,
+  usize => AtomicUsize
+);
+
+#[test]
+fn test_exchange_atomic() {
+  let mut hw = 5_u32;
+  let a: VolAddress<u32, Safe, Safe> =
+    unsafe { VolAddress::new(&mut hw as *mut u32 as usize) };
+  assert_eq!(unsafe { a.exchange_atomic(6) }, 5);
+  assert_eq!(a.read(), 6);
+}
+
+#[test]
+fn test_as_atomic() {
+  let mut hw = 5_u16;
+  let a: VolAddress<u16, Safe, Safe> =
+    unsafe { VolAddress::new(&mut hw as *mut u16 as usize) };
+  assert_eq!(unsafe { a.as_atomic() }.fetch_add(2, Ordering::SeqCst), 5);
+  assert_eq!(a.read(), 7);
+}
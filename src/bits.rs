@@ -0,0 +1,115 @@
+use super::*;
+use core::ops::Range;
+
+macro_rules! impl_bits {
+  ($($t:ty),*) => {
+    $(
+      impl<W, A> VolAddress<$t, Safe, W, A> {
+        /// Volatile reads the value, and gives just the bits in the range,
+        /// shifted down to the lowest bits.
+        ///
+        /// ## Panics
+        /// * If the range is backwards or goes past the last bit of the type.
+        #[inline]
+        #[track_caller]
+        pub fn read_bits(self, bits: Range<u32>) -> $t {
+          let (mask, shift) = bits_mask(bits, <$t>::BITS);
+          ((self.read() >> shift) as u64 & mask) as $t
+        }
+      }
+      impl<W, A> VolAddress<$t, Unsafe, W, A> {
+        /// Volatile reads the value, and gives just the bits in the range,
+        /// shifted down to the lowest bits.
+        ///
+        /// ## Panics
+        /// * If the range is backwards or goes past the last bit of the type.
+        ///
+        /// ## Safety
+        /// * The safety rules of reading this address depend on the device.
+        ///   Consult your hardware manual.
+        #[inline]
+        #[track_caller]
+        pub unsafe fn read_bits(self, bits: Range<u32>) -> $t {
+          let (mask, shift) = bits_mask(bits, <$t>::BITS);
+          ((self.read() >> shift) as u64 & mask) as $t
+        }
+      }
+      impl<A> VolAddress<$t, Safe, Safe, A> {
+        /// Reads the value, replaces just the bits in the range, and writes
+        /// the value back.
+        ///
+        /// The lowest bits of `value` are used, and any other bits of `value`
+        /// are ignored.
+        ///
+        /// ## Panics
+        /// * If the range is backwards or goes past the last bit of the type.
+        #[inline]
+        #[track_caller]
+        pub fn write_bits(self, bits: Range<u32>, value: $t) {
+          let (mask, shift) = bits_mask(bits, <$t>::BITS);
+          let mask = (mask as $t) << shift;
+          self.apply(|t| *t = (*t & !mask) | ((value << shift) & mask))
+        }
+      }
+      impl<R, W, A> VolAddress<$t, R, W, A>
+      where
+        (R, W): UnsafeReadWrite,
+      {
+        /// Reads the value, replaces just the bits in the range, and writes
+        /// the value back.
+        ///
+        /// The lowest bits of `value` are used, and any other bits of `value`
+        /// are ignored.
+        ///
+        /// ## Panics
+        /// * If the range is backwards or goes past the last bit of the type.
+        ///
+        /// ## Safety
+        /// * The safety rules of reading/writing this address depend on the
+        ///   device. Consult your hardware manual.
+        #[inline]
+        #[track_caller]
+        pub unsafe fn write_bits(self, bits: Range<u32>, value: $t) {
+          let (mask, shift) = bits_mask(bits, <$t>::BITS);
+          let mask = (mask as $t) << shift;
+          self.apply(|t| *t = (*t & !mask) | ((value << shift) & mask))
+        }
+      }
+    )*
+  };
+}
+impl_bits!(u8, u16, u32, u64, usize);
+
+/// Gives the unshifted mask and the shift for a range of bits within a type
+/// that's `type_bits` bits wide.
+#[inline]
+#[track_caller]
+fn bits_mask(bits: Range<u32>, type_bits: u32) -> (u64, u32) {
+  assert!(bits.start <= bits.end);
+  assert!(bits.end <= type_bits);
+  let width = bits.end - bits.start;
+  let mask = if width == 64 { u64::MAX } else { (1 << width) - 1 };
+  // An empty range (which might start at `type_bits`) uses a shift of 0, to
+  // keep the shifts in bounds.
+  (mask, if width == 0 { 0 } else { bits.start })
+}
+
+#[test]
+fn test_read_write_bits() {
+  let mut hw = 0xABCD_u16;
+  let a: VolAddress<u16, Safe, Safe> =
+    unsafe { VolAddress::new(&mut hw as *mut u16 as usize) };
+  assert_eq!(a.read_bits(4..8), 0xC);
+  assert_eq!(a.read_bits(0..16), 0xABCD);
+  a.write_bits(8..12, 0x12);
+  assert_eq!(hw, 0xA2CD);
+  a.write_bits(0..16, 0x1234);
+  assert_eq!(hw, 0x1234);
+  //
+  let mut hw = u64::MAX;
+  let b: VolAddress<u64, Safe, Safe> =
+    unsafe { VolAddress::new(&mut hw as *mut u64 as usize) };
+  assert_eq!(b.read_bits(0..64), u64::MAX);
+  b.write_bits(60..64, 0);
+  assert_eq!(b.read_bits(56..64), 0x0F);
+}
@@ -0,0 +1,89 @@
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+/// Cache maintenance operations for the target.
+///
+/// On targets with a data cache, memory that's shared with a DMA unit (or
+/// another bus master) needs cache maintenance around the CPU's accesses.
+/// Register your target's operations with [`set_cache_ops`] and then the bulk
+/// methods of [`VolRegion`](crate::VolRegion) will call them automatically.
+///
+/// Both functions are passed the start address and the size in bytes of the
+/// memory to operate on. They must be safe to call with any range: in
+/// particular, `invalidate` must not discard dirty data in partially covered
+/// cache lines (usually by cleaning those lines first).
+#[derive(Debug, Clone, Copy)]
+pub struct CacheOps {
+  /// Writes any dirty cache lines for the range back to memory.
+  pub clean: fn(address: usize, bytes: usize),
+  /// Discards any cached data for the range, so that the next read goes to
+  /// memory.
+  pub invalidate: fn(address: usize, bytes: usize),
+}
+
+static CACHE_OPS: AtomicPtr<CacheOps> = AtomicPtr::new(core::ptr::null_mut());
+
+/// Registers the cache maintenance operations to use.
+///
+/// This replaces any previously registered operations.
+#[inline]
+pub fn set_cache_ops(ops: &'static CacheOps) {
+  CACHE_OPS.store(ops as *const CacheOps as *mut CacheOps, Ordering::Release)
+}
+
+#[inline]
+fn cache_ops() -> Option<&'static CacheOps> {
+  // Safety: the only non-null pointers stored are from `&'static CacheOps`.
+  unsafe { CACHE_OPS.load(Ordering::Acquire).as_ref() }
+}
+
+/// Cleans the range, if cache ops are registered.
+#[inline]
+pub(crate) fn clean(address: usize, bytes: usize) {
+  if let Some(ops) = cache_ops() {
+    (ops.clean)(address, bytes)
+  }
+}
+
+/// Invalidates the range, if cache ops are registered.
+#[inline]
+pub(crate) fn invalidate(address: usize, bytes: usize) {
+  if let Some(ops) = cache_ops() {
+    (ops.invalidate)(address, bytes)
+  }
+}
+
+#[test]
+fn test_cache_ops() {
+  use crate::{Safe, VolAddress, VolRegion};
+  use core::sync::atomic::AtomicUsize;
+  // other tests might use the bulk methods at the same time, so we only count
+  // calls for our own region.
+  static TARGET: AtomicUsize = AtomicUsize::new(0);
+  static CLEANED: AtomicUsize = AtomicUsize::new(0);
+  static INVALIDATED: AtomicUsize = AtomicUsize::new(0);
+  static OPS: CacheOps = CacheOps {
+    clean: |address, bytes| {
+      if address == TARGET.load(Ordering::Relaxed) {
+        CLEANED.fetch_add(bytes, Ordering::Relaxed);
+      }
+    },
+    invalidate: |address, bytes| {
+      if address == TARGET.load(Ordering::Relaxed) {
+        INVALIDATED.fetch_add(bytes, Ordering::Relaxed);
+      }
+    },
+  };
+  //
+  let mut hw = [0_u16; 4];
+  TARGET.store(hw.as_mut_ptr() as usize, Ordering::Relaxed);
+  set_cache_ops(&OPS);
+  let region: VolRegion<u16, Safe, Safe> = unsafe {
+    VolRegion::from_raw_parts(VolAddress::new(hw.as_mut_ptr() as usize), 4)
+  };
+  region.write_from_slice(&[1, 2, 3, 4]);
+  assert_eq!(CLEANED.load(Ordering::Relaxed), 8);
+  let mut buffer = [0; 4];
+  region.read_to_slice(&mut buffer);
+  assert_eq!(INVALIDATED.load(Ordering::Relaxed), 8);
+  assert_eq!(buffer, [1, 2, 3, 4]);
+}
@@ -0,0 +1,159 @@
+use crate::{Safe, VolGrid2d};
+
+/// A simple text console that writes into a tile map.
+///
+/// Each byte of text is written into the grid as a tile entry, with the
+/// glyph index being the byte's value. Your tile graphics should therefore
+/// place the glyph for each ASCII character at the tile index of that
+/// character. Any bits in `tile_base` (such as palette selection bits) are
+/// combined with every glyph index.
+///
+/// The console tracks a cursor position. A `'\n'` moves to the start of the
+/// next line, a `'\r'` moves to the start of the current line, and text that
+/// goes past the right edge of the grid wraps to the next line. Moving past
+/// the bottom line scrolls the whole grid up by one row.
+///
+/// Non-ASCII characters are written as `'?'`.
+///
+/// Because it implements [`core::fmt::Write`], you can use `write!` and
+/// `writeln!` with the console.
+pub struct VolTextConsole<const WIDTH: usize, const HEIGHT: usize> {
+  grid: VolGrid2d<u16, Safe, Safe, WIDTH, HEIGHT>,
+  tile_base: u16,
+  x: usize,
+  y: usize,
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> VolTextConsole<WIDTH, HEIGHT> {
+  /// Makes a console over the grid, with the cursor at the top left.
+  ///
+  /// The grid's current contents are left as is.
+  #[inline]
+  #[must_use]
+  pub const fn new(
+    grid: VolGrid2d<u16, Safe, Safe, WIDTH, HEIGHT>, tile_base: u16,
+  ) -> Self {
+    Self { grid, tile_base, x: 0, y: 0 }
+  }
+
+  /// The grid that the console writes to.
+  #[inline]
+  #[must_use]
+  pub const fn grid(&self) -> VolGrid2d<u16, Safe, Safe, WIDTH, HEIGHT> {
+    self.grid
+  }
+
+  /// The cursor's current `(x,y)` position.
+  #[inline]
+  #[must_use]
+  pub const fn cursor(&self) -> (usize, usize) {
+    (self.x, self.y)
+  }
+
+  /// Moves the cursor to the `(x,y)` given.
+  ///
+  /// ## Panics
+  /// * If either coordinate is out of bounds this will panic.
+  #[inline]
+  #[track_caller]
+  pub fn set_cursor(&mut self, x: usize, y: usize) {
+    assert!(x < WIDTH);
+    assert!(y < HEIGHT);
+    self.x = x;
+    self.y = y;
+  }
+
+  /// Fills the whole grid with spaces and moves the cursor to the top left.
+  #[inline]
+  pub fn clear(&mut self) {
+    let blank = self.tile_base | u16::from(b' ');
+    (0..HEIGHT).for_each(|y| self.clear_row(y, blank));
+    self.x = 0;
+    self.y = 0;
+  }
+
+  /// Writes a single byte at the cursor, handling control characters.
+  #[inline]
+  pub fn write_byte(&mut self, b: u8) {
+    match b {
+      b'\n' => self.new_line(),
+      b'\r' => self.x = 0,
+      _ => {
+        if self.x >= WIDTH {
+          self.new_line();
+        }
+        self.grid.index(self.x, self.y).write(self.tile_base | u16::from(b));
+        self.x += 1;
+      }
+    }
+  }
+
+  fn new_line(&mut self) {
+    self.x = 0;
+    if self.y + 1 < HEIGHT {
+      self.y += 1;
+    } else {
+      self.scroll_up();
+    }
+  }
+
+  fn scroll_up(&mut self) {
+    for y in 1..HEIGHT {
+      // `y` and `y - 1` are both in bounds, so these can't fail.
+      let src = self.grid.get_row(y).unwrap();
+      let dst = self.grid.get_row(y - 1).unwrap();
+      src.iter().zip(dst.iter()).for_each(|(s, d)| d.write(s.read()));
+    }
+    self.clear_row(HEIGHT - 1, self.tile_base | u16::from(b' '));
+  }
+
+  fn clear_row(&self, y: usize, blank: u16) {
+    if let Some(row) = self.grid.get_row(y) {
+      row.iter().for_each(|a| a.write(blank));
+    }
+  }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> core::fmt::Write
+  for VolTextConsole<WIDTH, HEIGHT>
+{
+  #[inline]
+  fn write_str(&mut self, s: &str) -> core::fmt::Result {
+    for c in s.chars() {
+      self.write_byte(if c.is_ascii() { c as u8 } else { b'?' });
+    }
+    Ok(())
+  }
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> core::fmt::Debug
+  for VolTextConsole<WIDTH, HEIGHT>
+{
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(
+      f,
+      "VolTextConsole<w{width}, h{height}>({address:#X}, cursor: ({x}, {y}))",
+      width = WIDTH,
+      height = HEIGHT,
+      address = self.grid.as_usize(),
+      x = self.x,
+      y = self.y,
+    )
+  }
+}
+
+#[test]
+fn test_voltextconsole() {
+  use core::fmt::Write;
+  let mut hw = [0_u16; 3 * 2];
+  let grid: VolGrid2d<u16, Safe, Safe, 3, 2> =
+    unsafe { VolGrid2d::new(hw.as_mut_ptr() as usize) };
+  let mut console = VolTextConsole::new(grid, 0x100);
+  console.clear();
+  write!(console, "ab\ncdef").unwrap();
+  assert_eq!(console.cursor(), (1, 1));
+  let text = hw.map(|t| (t & 0xFF) as u8);
+  assert_eq!(&text, b"cdef  ");
+  assert_eq!(hw[0] & 0xFF00, 0x100);
+}
@@ -0,0 +1,46 @@
+use super::*;
+
+impl<T, A> VolAddress<T, Safe, Safe, A>
+where
+  T: Copy,
+{
+  /// Reads the address, applies the operation, and writes back the new value,
+  /// all within a critical section.
+  ///
+  /// This uses [`critical_section::with`], so no interrupt handler (or other
+  /// core, depending on your critical section implementation) can access the
+  /// address between the read and the write.
+  #[inline]
+  pub fn apply_atomic<F: FnOnce(&mut T)>(self, op: F) {
+    critical_section::with(|_| self.apply(op))
+  }
+}
+impl<T, R, W, A> VolAddress<T, R, W, A>
+where
+  T: Copy,
+  (R, W): UnsafeReadWrite,
+{
+  /// Reads the address, applies the operation, and writes back the new value,
+  /// all within a critical section.
+  ///
+  /// This uses [`critical_section::with`], so no interrupt handler (or other
+  /// core, depending on your critical section implementation) can access the
+  /// address between the read and the write.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading/writing this address depend on the device.
+  ///   Consult your hardware manual.
+  #[inline]
+  pub unsafe fn apply_atomic<F: FnOnce(&mut T)>(self, op: F) {
+    critical_section::with(|_| self.apply_raw(op))
+  }
+}
+
+#[test]
+fn test_apply_atomic() {
+  let mut hw = 5_u32;
+  let a: VolAddress<u32, Safe, Safe> =
+    unsafe { VolAddress::new(&mut hw as *mut u32 as usize) };
+  a.apply_atomic(|x| *x += 1);
+  assert_eq!(a.read(), 6);
+}
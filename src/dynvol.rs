@@ -0,0 +1,135 @@
+use super::*;
+
+/// The parts of a volatile location that don't depend on its type.
+///
+/// This is the supertrait of [`DynVolRead`] and [`DynVolWrite`].
+pub trait DynVolAddress {
+  /// The address of the location.
+  fn address(&self) -> usize;
+
+  /// The width of the location's value, in bytes.
+  fn width(&self) -> usize;
+}
+
+/// An object-safe way to read a volatile location.
+///
+/// This is implemented by safely readable [`VolAddress`] values of `u8`,
+/// `u16`, and `u32`. It allows code such as a debug console or a scripting
+/// layer to keep a table of `&dyn DynVolRead` values without needing to know
+/// each register's exact type.
+pub trait DynVolRead: DynVolAddress {
+  /// Volatile reads the value, zero-extended to a `u32`.
+  fn read_u32(&self) -> u32;
+}
+
+/// An object-safe way to write a volatile location.
+///
+/// This is implemented by safely writable [`VolAddress`] values of `u8`,
+/// `u16`, and `u32`. It allows code such as a debug console or a scripting
+/// layer to keep a table of `&dyn DynVolWrite` values without needing to know
+/// each register's exact type.
+pub trait DynVolWrite: DynVolAddress {
+  /// Volatile writes the value, truncated to the location's width.
+  fn write_u32(&self, u: u32);
+}
+
+macro_rules! impl_dyn_vol {
+  ($($t:ty),*) => {
+    $(
+      impl<R, W, A> DynVolAddress for VolAddress<$t, R, W, A> {
+        #[inline]
+        fn address(&self) -> usize {
+          self.as_usize()
+        }
+        #[inline]
+        fn width(&self) -> usize {
+          core::mem::size_of::<$t>()
+        }
+      }
+      impl<W, A> DynVolRead for VolAddress<$t, Safe, W, A> {
+        #[inline]
+        fn read_u32(&self) -> u32 {
+          u32::from(self.read())
+        }
+      }
+      impl<R, A> DynVolWrite for VolAddress<$t, R, Safe, A> {
+        #[inline]
+        fn write_u32(&self, u: u32) {
+          self.write(u as $t)
+        }
+      }
+    )*
+  };
+}
+impl_dyn_vol!(u8, u16, u32);
+
+/// Writes a dump of the named registers given, one per line.
+///
+/// Each register is read once and written out as its name, address, and value,
+/// with the value padded to the register's width. For example:
+///
+/// ```text
+/// DISPCNT @ 0x04000000 = 0x0403
+/// ```
+///
+/// Keeping one table of a peripheral's readable registers and passing it here
+/// makes for an easy debug dump during bring-up. This crate has no way to
+/// generate that table from a peripheral declaration, so you'll have to list
+/// the registers yourself.
+pub fn dump_registers<Wr: core::fmt::Write>(
+  out: &mut Wr, registers: &[(&str, &dyn DynVolRead)],
+) -> core::fmt::Result {
+  for (name, reg) in registers {
+    writeln!(
+      out,
+      "{name} @ {address:#010X} = {value:#0digits$X}",
+      name = name,
+      address = reg.address(),
+      value = reg.read_u32(),
+      digits = 2 + reg.width() * 2,
+    )?;
+  }
+  Ok(())
+}
+
+#[test]
+fn test_dyn_vol() {
+  let mut hw = [0_u16; 2];
+  let a: VolAddress<u16, Safe, Safe> =
+    unsafe { VolAddress::new(hw.as_mut_ptr() as usize) };
+  let b: VolAddress<u8, Safe, Safe> =
+    unsafe { VolAddress::new(hw.as_mut_ptr() as usize + 2) };
+  let regs: [&dyn DynVolWrite; 2] = [&a, &b];
+  regs.iter().for_each(|r| r.write_u32(0x1234_5678));
+  let regs: [&dyn DynVolRead; 2] = [&a, &b];
+  assert_eq!(regs[0].read_u32(), 0x5678);
+  assert_eq!(regs[0].width(), 2);
+  assert_eq!(regs[1].read_u32(), 0x78);
+  assert_eq!(regs[1].width(), 1);
+  assert_eq!(regs[1].address(), a.as_usize() + 2);
+}
+
+#[test]
+fn test_dump_registers() {
+  struct Buf([u8; 64], usize);
+  impl core::fmt::Write for Buf {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+      let end = self.1 + s.len();
+      self
+        .0
+        .get_mut(self.1..end)
+        .ok_or(core::fmt::Error)?
+        .copy_from_slice(s.as_bytes());
+      self.1 = end;
+      Ok(())
+    }
+  }
+  let mut hw = 0x403_u16;
+  let a: VolAddress<u16, Safe, ()> =
+    unsafe { VolAddress::new(&mut hw as *mut u16 as usize) };
+  let mut buf = Buf([0; 64], 0);
+  dump_registers(&mut buf, &[("CTRL", &a)]).unwrap();
+  let text = core::str::from_utf8(&buf.0[..buf.1]).unwrap();
+  assert!(text.starts_with("CTRL @ 0x"));
+  assert!(text.ends_with(" = 0x0403\n"));
+}
@@ -0,0 +1,156 @@
+/// An integer stored in memory in big-endian byte order.
+///
+/// Use this as the `T` type of an address for registers that are big-endian
+/// regardless of the CPU's byte order (eg: on some network controllers and
+/// bridged busses). The value is converted to and from the CPU's byte order by
+/// [`new`](Self::new) and [`get`](Self::get), so each access site doesn't
+/// need to remember to swap the bytes.
+///
+/// ```
+/// # use voladdress::*;
+/// let mut hw = 0x1234_u16.to_be();
+/// let reg: VolAddress<BigEndian<u16>, Safe, Safe> =
+///   unsafe { VolAddress::new(&mut hw as *mut u16 as usize) };
+/// assert_eq!(reg.read().get(), 0x1234);
+/// reg.write(BigEndian::new(0x5678));
+/// assert_eq!(u16::from_be(hw), 0x5678);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BigEndian<T>(T);
+
+/// An integer stored in memory in little-endian byte order.
+///
+/// This works like [`BigEndian`], but for little-endian registers.
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LittleEndian<T>(T);
+
+/// An integer type that can be converted between byte orders.
+///
+/// This is implemented for all of the multi-byte integer types, and lets them
+/// be used with [`BigEndian`] and [`LittleEndian`].
+pub trait EndianInt: Copy {
+  /// Converts from the CPU's byte order to big-endian.
+  fn to_big_endian(self) -> Self;
+  /// Converts from big-endian to the CPU's byte order.
+  fn big_endian_to_native(self) -> Self;
+  /// Converts from the CPU's byte order to little-endian.
+  fn to_little_endian(self) -> Self;
+  /// Converts from little-endian to the CPU's byte order.
+  fn little_endian_to_native(self) -> Self;
+}
+
+macro_rules! impl_endian_int {
+  ($($t:ty),*) => {
+    $(
+      impl EndianInt for $t {
+        #[inline]
+        fn to_big_endian(self) -> Self {
+          self.to_be()
+        }
+        #[inline]
+        fn big_endian_to_native(self) -> Self {
+          <$t>::from_be(self)
+        }
+        #[inline]
+        fn to_little_endian(self) -> Self {
+          self.to_le()
+        }
+        #[inline]
+        fn little_endian_to_native(self) -> Self {
+          <$t>::from_le(self)
+        }
+      }
+    )*
+  };
+}
+impl_endian_int!(u16, u32, u64, u128, usize, i16, i32, i64, i128, isize);
+
+impl<T: EndianInt> BigEndian<T> {
+  /// Stores the value in big-endian byte order.
+  #[inline]
+  #[must_use]
+  pub fn new(t: T) -> Self {
+    Self(t.to_big_endian())
+  }
+
+  /// Gets the value in the CPU's byte order.
+  #[inline]
+  #[must_use]
+  pub fn get(self) -> T {
+    self.0.big_endian_to_native()
+  }
+}
+
+impl<T: EndianInt> LittleEndian<T> {
+  /// Stores the value in little-endian byte order.
+  #[inline]
+  #[must_use]
+  pub fn new(t: T) -> Self {
+    Self(t.to_little_endian())
+  }
+
+  /// Gets the value in the CPU's byte order.
+  #[inline]
+  #[must_use]
+  pub fn get(self) -> T {
+    self.0.little_endian_to_native()
+  }
+}
+
+impl<T> BigEndian<T> {
+  /// Wraps a value that's already in big-endian byte order.
+  #[inline]
+  #[must_use]
+  pub const fn from_raw(raw: T) -> Self {
+    Self(raw)
+  }
+
+  /// Gets the value as it's stored in memory, without any byte swap.
+  #[inline]
+  #[must_use]
+  pub fn to_raw(self) -> T {
+    self.0
+  }
+}
+
+impl<T> LittleEndian<T> {
+  /// Wraps a value that's already in little-endian byte order.
+  #[inline]
+  #[must_use]
+  pub const fn from_raw(raw: T) -> Self {
+    Self(raw)
+  }
+
+  /// Gets the value as it's stored in memory, without any byte swap.
+  #[inline]
+  #[must_use]
+  pub fn to_raw(self) -> T {
+    self.0
+  }
+}
+
+impl<T: EndianInt> From<T> for BigEndian<T> {
+  #[inline]
+  fn from(t: T) -> Self {
+    Self::new(t)
+  }
+}
+
+impl<T: EndianInt> From<T> for LittleEndian<T> {
+  #[inline]
+  fn from(t: T) -> Self {
+    Self::new(t)
+  }
+}
+
+#[test]
+fn test_endian() {
+  let be = BigEndian::new(0x1234_u16);
+  assert_eq!(be.to_raw().to_ne_bytes(), [0x12, 0x34]);
+  assert_eq!(be.get(), 0x1234);
+  let le = LittleEndian::new(0x1234_u16);
+  assert_eq!(le.to_raw().to_ne_bytes(), [0x34, 0x12]);
+  assert_eq!(le.get(), 0x1234);
+}
@@ -0,0 +1,189 @@
+use super::*;
+use core::sync::atomic::{AtomicPtr, AtomicUsize};
+
+/// One slot of a journal's ring buffer.
+struct JournalSlot {
+  /// 0 when empty, 1 for a read, 2 for a write.
+  kind: AtomicUsize,
+  address: AtomicUsize,
+  size: AtomicUsize,
+}
+
+#[allow(clippy::declare_interior_mutable_const)]
+const EMPTY_SLOT: JournalSlot = JournalSlot {
+  kind: AtomicUsize::new(0),
+  address: AtomicUsize::new(0),
+  size: AtomicUsize::new(0),
+};
+
+/// A single access recorded by a [`VolJournal`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JournalEntry {
+  /// The address that was accessed.
+  pub address: usize,
+  /// If the access was a write (otherwise it was a read).
+  pub write: bool,
+  /// The size (in bytes) of the access.
+  pub size: usize,
+}
+
+/// A fixed size journal of the most recent volatile accesses.
+///
+/// Once a journal is [installed](VolJournal::install), every `read` and
+/// `write` of a [`VolAddress`] records the address, the kind of access, and
+/// the size into the journal's ring buffer, keeping the last `N` accesses.
+/// Other methods record their accesses if they are built on `read` and
+/// `write`, but the unaligned and atomic methods don't record anything.
+///
+/// The value itself isn't recorded, because an arbitrary `T` might have
+/// padding bytes, and those can't be copied out as an integer.
+///
+/// The journal is meant to be a `static`, so that a panic handler can print
+/// what a driver did right before it failed.
+///
+/// Each access is recorded within a [`critical_section::with`] call, so an
+/// interrupt handler's accesses can't be lost or mixed up with the access
+/// that it interrupted.
+#[repr(C)]
+pub struct VolJournal<const N: usize> {
+  // Note: the fields before `slots` must not depend on `N`, because the
+  // installed journal is accessed without knowing `N`.
+  capacity: usize,
+  next: AtomicUsize,
+  slots: [JournalSlot; N],
+}
+
+static JOURNAL: AtomicPtr<VolJournal<0>> =
+  AtomicPtr::new(core::ptr::null_mut());
+
+impl<const N: usize> VolJournal<N> {
+  /// Makes a new, empty journal.
+  #[inline]
+  #[must_use]
+  pub const fn new() -> Self {
+    Self { capacity: N, next: AtomicUsize::new(0), slots: [EMPTY_SLOT; N] }
+  }
+
+  /// Makes this the journal that accesses are recorded into.
+  ///
+  /// This replaces any previously installed journal.
+  #[inline]
+  pub fn install(&'static self) {
+    JOURNAL.store(
+      self as *const Self as *const VolJournal<0> as *mut VolJournal<0>,
+      Ordering::Release,
+    )
+  }
+
+  /// Stops recording accesses into any journal.
+  #[inline]
+  pub fn uninstall() {
+    JOURNAL.store(core::ptr::null_mut(), Ordering::Release)
+  }
+
+  /// Iterates over the recorded accesses, from oldest to newest.
+  #[inline]
+  pub fn entries(&self) -> impl Iterator<Item = JournalEntry> + '_ {
+    let next = self.next.load(Ordering::Relaxed);
+    let count = next.min(N);
+    (next - count..next).filter_map(move |i| {
+      let slot = &self.slots[i % N];
+      let write = match slot.kind.load(Ordering::Relaxed) {
+        1 => false,
+        2 => true,
+        _ => return None,
+      };
+      Some(JournalEntry {
+        address: slot.address.load(Ordering::Relaxed),
+        write,
+        size: slot.size.load(Ordering::Relaxed),
+      })
+    })
+  }
+}
+
+impl<const N: usize> Default for VolJournal<N> {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<const N: usize> core::fmt::Debug for VolJournal<N> {
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_list().entries(self.entries()).finish()
+  }
+}
+
+/// Records an access into the journal at `p`.
+///
+/// ## Safety
+/// * `p` must point to a live `VolJournal<N>` (for any `N`), cast to
+///   `VolJournal<0>`.
+unsafe fn push_raw(
+  p: *const VolJournal<0>, address: usize, size: usize, write: bool,
+) {
+  // Note: all fields before the slots have the same layout for any `N`, and
+  // the journal has `capacity` slots.
+  let capacity = (*p).capacity;
+  if capacity == 0 {
+    return;
+  }
+  critical_section::with(|_| {
+    let i = (*p).next.load(Ordering::Relaxed);
+    (*p).next.store(i.wrapping_add(1), Ordering::Relaxed);
+    let slots = core::ptr::addr_of!((*p).slots) as *const JournalSlot;
+    let slot = &*slots.add(i % capacity);
+    slot.kind.store(if write { 2 } else { 1 }, Ordering::Relaxed);
+    slot.address.store(address, Ordering::Relaxed);
+    slot.size.store(size, Ordering::Relaxed);
+  })
+}
+
+/// Records an access into the installed journal, if any.
+#[inline]
+pub(crate) fn record<T>(address: usize, write: bool) {
+  let p = JOURNAL.load(Ordering::Acquire);
+  if p.is_null() {
+    return;
+  }
+  // Safety: the pointer came from a `&'static VolJournal<N>`.
+  unsafe { push_raw(p, address, core::mem::size_of::<T>(), write) }
+}
+
+#[test]
+fn test_voljournal() {
+  // Other tests access memory at the same time, so this uses its own journal
+  // rather than the installed one.
+  let journal: VolJournal<2> = VolJournal::new();
+  assert_eq!(journal.entries().count(), 0);
+  let p = &journal as *const VolJournal<2> as *const VolJournal<0>;
+  unsafe {
+    push_raw(p, 0x1000, 4, true);
+    push_raw(p, 0x1004, 2, false);
+    push_raw(p, 0x1008, 1, true);
+  }
+  let entries: [JournalEntry; 2] = [
+    JournalEntry { address: 0x1004, write: false, size: 2 },
+    JournalEntry { address: 0x1008, write: true, size: 1 },
+  ];
+  assert!(journal.entries().eq(entries.iter().copied()));
+}
+
+#[test]
+fn test_voljournal_install() {
+  static JOURNAL_TEST: VolJournal<1024> = VolJournal::new();
+  let mut hw = [0_u32; 1];
+  let a: VolAddress<u32, Safe, Safe> =
+    unsafe { VolAddress::new(hw.as_mut_ptr() as usize) };
+  JOURNAL_TEST.install();
+  a.write(5);
+  VolJournal::<1024>::uninstall();
+  // Other tests' accesses may also be recorded, so only look for our own.
+  assert!(
+    JOURNAL_TEST.entries().any(
+      |e| e == JournalEntry { address: a.as_usize(), write: true, size: 4 }
+    )
+  );
+}
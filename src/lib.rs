@@ -108,8 +108,10 @@
 
 use core::{
   marker::PhantomData,
+  mem::MaybeUninit,
   num::NonZeroUsize,
   ptr::{read_volatile, write_volatile},
+  sync::atomic::{fence, Ordering},
 };
 
 mod voladdress_;
@@ -127,9 +129,89 @@ pub use volgrid2d::*;
 mod volgrid2d_strided;
 pub use volgrid2d_strided::*;
 
+mod volgrid2d_rgb;
+pub use volgrid2d_rgb::*;
+
 mod volregion;
 pub use volregion::*;
 
+mod volstridedregion;
+pub use volstridedregion::*;
+
+mod volcursor;
+pub use volcursor::*;
+
+mod volshadow;
+pub use volshadow::*;
+
+mod dynvol;
+pub use dynvol::*;
+
+mod volwatch;
+pub use volwatch::*;
+
+mod once;
+pub use once::*;
+
+mod endian;
+pub use endian::*;
+
+mod volwide;
+pub use volwide::*;
+
+mod volpair;
+pub use volpair::*;
+
+mod volsnapshot;
+pub use volsnapshot::*;
+
+mod volsemaphore;
+pub use volsemaphore::*;
+
+mod volchannel;
+pub use volchannel::*;
+
+mod volfield;
+pub use volfield::*;
+
+mod wait;
+pub use wait::*;
+
+mod spec;
+pub use spec::*;
+
+mod memrange;
+pub use memrange::*;
+
+mod bits;
+
+mod withreset;
+pub use withreset::*;
+
+#[cfg(feature = "console")]
+mod console;
+#[cfg(feature = "console")]
+pub use console::*;
+
+#[cfg(feature = "atomics")]
+mod atomics;
+
+#[cfg(feature = "critical-section")]
+mod critical;
+
+#[cfg(feature = "atomic_rmw")]
+mod atomic_rmw;
+
+#[cfg(feature = "journal")]
+mod journal;
+#[cfg(feature = "journal")]
+pub use journal::*;
+
+#[cfg(feature = "cache_ops")]
+mod cache_ops;
+#[cfg(feature = "cache_ops")]
+pub use cache_ops::*;
+
 /// Lets you put "Safe" into a generic type parameter.
 ///
 /// This type affects the read and write methods of the volatile address types,
@@ -143,3 +225,145 @@ pub struct Safe;
 /// but has no effect on its own.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Unsafe;
+
+/// Lets you put "write 1 to clear" into the `W` type parameter.
+///
+/// Interrupt flag registers often work this way: writing a 1 bit clears that
+/// flag, and writing a 0 bit leaves it alone. With this type as the `W`
+/// parameter, the only way to write is with the `clear` method (eg:
+/// [`VolAddress::clear`]), which writes the mask directly. This prevents
+/// accidentally doing a read-modify-write, which would clear every pending
+/// flag.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct W1C;
+
+/// A pair of read and write permissions, `(R, W)`, where both are [`Safe`]
+/// or [`Unsafe`], and at least one of them is `Unsafe`.
+///
+/// The read-modify-write methods of the volatile address types (such as
+/// `apply`) are safe when the pair is `(Safe, Safe)`. For every pair with this
+/// trait, a single generic impl gives an `unsafe` version of each method
+/// instead.
+///
+/// This trait is sealed.
+pub trait UnsafeReadWrite: permission::Sealed {}
+mod permission {
+  use super::{Safe, Unsafe};
+  pub trait Sealed {}
+  impl Sealed for (Unsafe, Safe) {}
+  impl Sealed for (Safe, Unsafe) {}
+  impl Sealed for (Unsafe, Unsafe) {}
+}
+impl UnsafeReadWrite for (Unsafe, Safe) {}
+impl UnsafeReadWrite for (Safe, Unsafe) {}
+impl UnsafeReadWrite for (Unsafe, Unsafe) {}
+
+/// Lets you put "read to clear" into the `R` type parameter.
+///
+/// Some status registers clear themselves when read, so reading them has a
+/// side effect. With this type as the `R` parameter, the only way to read is
+/// with the `read_and_clear` method (eg: [`VolAddress::read_and_clear`]), so
+/// the side effect is clear at each access site.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RC;
+
+/// Lets you put "safe, after `N` dummy reads" into the `R` type parameter.
+///
+/// Some hardware needs a few reads to be done and thrown away before a read
+/// gives valid data (eg: because of a read pipeline or latch). With this type
+/// as the `R` parameter, `read` (eg: [`VolAddress::read`]) does the dummy
+/// reads first, so that the quirk is part of the memory map declaration.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DummyReads<const N: usize>;
+
+/// Lets you put "write once" into the `W` type parameter.
+///
+/// Some lock or configuration registers can only be written once after a
+/// reset, and any later writes are silently ignored by the hardware. With
+/// this type as the `W` parameter, each write (eg:
+/// [`VolAddress::write_once`]) uses up a [`WriteOnceToken<K>`], so trying to
+/// write twice is a compile error.
+///
+/// The `K` type is a "key" type that you declare for each such register, so
+/// that the tokens of different registers can't be mixed up.
+pub struct WriteOnce<K>(PhantomData<K>);
+
+// Manual impls, so that they don't need any bounds on `K`.
+impl<K> Clone for WriteOnce<K> {
+  #[inline]
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+impl<K> Copy for WriteOnce<K> {}
+impl<K> PartialEq for WriteOnce<K> {
+  #[inline]
+  fn eq(&self, _: &Self) -> bool {
+    true
+  }
+}
+impl<K> Eq for WriteOnce<K> {}
+impl<K> core::fmt::Debug for WriteOnce<K> {
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "WriteOnce<{key}>", key = core::any::type_name::<K>())
+  }
+}
+
+/// The permission to write a [`WriteOnce`] address a single time.
+///
+/// This is not `Copy` or `Clone`, so it can only be used once.
+pub struct WriteOnceToken<K>(PhantomData<K>);
+
+impl<K> WriteOnceToken<K> {
+  /// Makes the token.
+  ///
+  /// ## Safety
+  /// * Only one token with this `K` type can be made for the whole program.
+  ///   Usually the token is made as part of the boot code and then passed to
+  ///   the code that sets up the register.
+  #[inline]
+  #[must_use]
+  pub const unsafe fn new() -> Self {
+    Self(PhantomData)
+  }
+}
+
+impl<K> core::fmt::Debug for WriteOnceToken<K> {
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "WriteOnceToken<{key}>", key = core::any::type_name::<K>())
+  }
+}
+
+/// A type that has a known hardware reset value.
+///
+/// Implement this for your register types so that the `reset` methods of the
+/// volatile address types can return the hardware to its power-on state.
+pub trait ResetValue {
+  /// The value that the hardware holds after a reset.
+  const RESET_VALUE: Self;
+}
+
+/// A proof that every address in the address space `A` is safe to read.
+///
+/// A HAL can give out addresses without read permission, and then later let a
+/// trusted subsystem upgrade them with
+/// [`upgrade_read_with`](VolAddress::upgrade_read_with) by giving it a value of
+/// the proof type. Usually the proof type is a zero-sized type which only the
+/// HAL can construct.
+///
+/// ## Safety
+/// * While any value of the type exists, it must be safe to read any address
+///   in the address space `A` (with the address's own `T` type).
+pub unsafe trait ReadProof<A> {}
+
+/// A proof that every address in the address space `A` is safe to write.
+///
+/// This works like [`ReadProof`], but for
+/// [`upgrade_write_with`](VolAddress::upgrade_write_with).
+///
+/// ## Safety
+/// * While any value of the type exists, it must be safe to write any value
+///   of the address's `T` type to any address in the address space `A`.
+pub unsafe trait WriteProof<A> {}
@@ -0,0 +1,97 @@
+use super::*;
+
+/// A named range of addresses, from `start` up to (but not including) `end`.
+///
+/// This is for checking addresses that come from somewhere you don't control
+/// (eg: a debug monitor command, or a script) against the windows of memory
+/// that they're allowed to use.
+///
+/// ```
+/// # use voladdress::*;
+/// pub const IWRAM: MemRange = MemRange::new(0x0300_0000, 0x0300_8000);
+///
+/// let a: VolAddress<u32, Safe, Safe> = unsafe { VolAddress::new(0x0300_7FFC) };
+/// assert!(a.is_in(IWRAM));
+/// let b: VolAddress<u32, Safe, Safe> = unsafe { VolAddress::new(0x0300_7FFE) };
+/// assert!(!b.is_in(IWRAM));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MemRange {
+  /// The first address in the range.
+  pub start: usize,
+  /// The first address *after* the range.
+  pub end: usize,
+}
+
+impl MemRange {
+  /// Makes a range.
+  ///
+  /// ## Panics
+  /// * If `end` is less than `start`.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub const fn new(start: usize, end: usize) -> Self {
+    assert!(start <= end);
+    Self { start, end }
+  }
+
+  /// The number of bytes in the range.
+  #[inline]
+  #[must_use]
+  #[allow(clippy::len_without_is_empty)]
+  pub const fn len(self) -> usize {
+    self.end - self.start
+  }
+
+  /// If the address is within the range.
+  #[inline]
+  #[must_use]
+  pub const fn contains(self, address: usize) -> bool {
+    self.start <= address && address < self.end
+  }
+
+  /// If the `bytes` bytes starting at `address` are all within the range.
+  #[inline]
+  #[must_use]
+  pub const fn contains_span(self, address: usize, bytes: usize) -> bool {
+    self.start <= address && address <= self.end && bytes <= self.end - address
+  }
+}
+
+impl<T, R, W, A> VolAddress<T, R, W, A> {
+  /// If every byte of the `T` at this address is within the range.
+  #[inline]
+  #[must_use]
+  pub const fn is_in(self, range: MemRange) -> bool {
+    range.contains_span(self.as_usize(), core::mem::size_of::<T>())
+  }
+}
+
+impl<T, R, W> VolRegion<T, R, W> {
+  /// If every byte of the region is within the range.
+  #[inline]
+  #[must_use]
+  pub const fn is_within(self, range: MemRange) -> bool {
+    match self.len.checked_mul(core::mem::size_of::<T>()) {
+      Some(bytes) => range.contains_span(self.as_usize(), bytes),
+      None => false,
+    }
+  }
+}
+
+#[test]
+fn test_memrange() {
+  const RANGE: MemRange = MemRange::new(0x1000, 0x1010);
+  assert_eq!(RANGE.len(), 0x10);
+  assert!(RANGE.contains(0x100F));
+  assert!(!RANGE.contains(0x1010));
+  let region: VolRegion<u32, Safe, Safe> =
+    unsafe { VolRegion::from_raw_parts(VolAddress::new(0x1008), 2) };
+  assert!(region.is_within(RANGE));
+  let region: VolRegion<u32, Safe, Safe> =
+    unsafe { VolRegion::from_raw_parts(VolAddress::new(0x1008), 3) };
+  assert!(!region.is_within(RANGE));
+  let a: VolAddress<u8, Safe, Safe> = unsafe { VolAddress::new(0xFFF) };
+  assert!(!a.is_in(RANGE));
+}
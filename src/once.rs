@@ -0,0 +1,169 @@
+use super::*;
+use core::sync::atomic::AtomicUsize;
+
+/// A cell that holds a [`VolAddress`] which is set once at runtime.
+///
+/// This is for addresses that aren't known until the program is running (eg:
+/// found by probing the hardware during boot). Once the address is set it can
+/// be read from anywhere, such as from a `static`, almost like it was a
+/// `const` declaration.
+///
+/// This only uses atomic loads and stores, so it works even on targets that
+/// don't have atomic read-modify-write operations.
+pub struct OnceVolAddress<T, R, W> {
+  address: AtomicUsize,
+  target: PhantomData<VolAddress<T, R, W>>,
+}
+
+impl<T, R, W> OnceVolAddress<T, R, W> {
+  /// Makes a new, empty cell.
+  #[inline]
+  #[must_use]
+  pub const fn new() -> Self {
+    Self { address: AtomicUsize::new(0), target: PhantomData }
+  }
+
+  /// Sets the address held by the cell.
+  ///
+  /// If the cell is already set then it's not changed, and the address given
+  /// is returned as an error.
+  ///
+  /// ## Safety
+  /// * This must not be called while another call to `set` on the same cell is
+  ///   in progress (eg: from an interrupt handler). Usually the cell is set
+  ///   only by the boot code.
+  #[inline]
+  pub unsafe fn set(
+    &self, addr: VolAddress<T, R, W>,
+  ) -> Result<(), VolAddress<T, R, W>> {
+    if self.address.load(Ordering::Acquire) != 0 {
+      return Err(addr);
+    }
+    self.address.store(addr.as_usize(), Ordering::Release);
+    Ok(())
+  }
+
+  /// Gets the address held by the cell, or `None` if it hasn't been set yet.
+  #[inline]
+  #[must_use]
+  pub fn get(&self) -> Option<VolAddress<T, R, W>> {
+    // Safety: only a `VolAddress` can be stored in the cell, so a non-zero
+    // value is a valid address.
+    unsafe { VolAddress::try_new(self.address.load(Ordering::Acquire)) }
+  }
+}
+
+impl<T, R, W> Default for OnceVolAddress<T, R, W> {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<T, R, W> core::fmt::Debug for OnceVolAddress<T, R, W> {
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_tuple("OnceVolAddress").field(&self.get()).finish()
+  }
+}
+
+/// A cell that holds a [`VolRegion`] which is set once at runtime.
+///
+/// This is for regions that aren't known until the program is running (eg:
+/// the size of RAM found by probing, or a PCI BAR assigned during boot). Once
+/// the region is set it can be read from anywhere, such as from a `static`,
+/// almost like it was a `const` declaration.
+///
+/// This only uses atomic loads and stores, so it works even on targets that
+/// don't have atomic read-modify-write operations.
+pub struct OnceVolRegion<T, R, W> {
+  address: AtomicUsize,
+  len: AtomicUsize,
+  target: PhantomData<VolRegion<T, R, W>>,
+}
+
+impl<T, R, W> OnceVolRegion<T, R, W> {
+  /// Makes a new, empty cell.
+  #[inline]
+  #[must_use]
+  pub const fn new() -> Self {
+    Self {
+      address: AtomicUsize::new(0),
+      len: AtomicUsize::new(0),
+      target: PhantomData,
+    }
+  }
+
+  /// Sets the region held by the cell.
+  ///
+  /// If the cell is already set then it's not changed, and the region given
+  /// is returned as an error.
+  ///
+  /// ## Safety
+  /// * This must not be called while another call to `set` on the same cell is
+  ///   in progress (eg: from an interrupt handler). Usually the cell is set
+  ///   only by the boot code.
+  #[inline]
+  pub unsafe fn set(
+    &self, region: VolRegion<T, R, W>,
+  ) -> Result<(), VolRegion<T, R, W>> {
+    if self.address.load(Ordering::Acquire) != 0 {
+      return Err(region);
+    }
+    // The length is stored first, so that anyone who sees the address will
+    // also see the correct length.
+    self.len.store(region.len(), Ordering::Relaxed);
+    self.address.store(region.as_usize(), Ordering::Release);
+    Ok(())
+  }
+
+  /// Gets the region held by the cell, or `None` if it hasn't been set yet.
+  #[inline]
+  #[must_use]
+  pub fn get(&self) -> Option<VolRegion<T, R, W>> {
+    let address = self.address.load(Ordering::Acquire);
+    let len = self.len.load(Ordering::Relaxed);
+    // Safety: only a `VolRegion` can be stored in the cell, so a non-zero
+    // address (and the length stored before it) is a valid region.
+    unsafe { VolRegion::try_new(address, len) }
+  }
+}
+
+impl<T, R, W> Default for OnceVolRegion<T, R, W> {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<T, R, W> core::fmt::Debug for OnceVolRegion<T, R, W> {
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_tuple("OnceVolRegion").field(&self.get()).finish()
+  }
+}
+
+#[test]
+fn test_oncevolregion() {
+  static RAM: OnceVolRegion<u32, Safe, Safe> = OnceVolRegion::new();
+  let mut hw = [0_u32; 4];
+  assert!(RAM.get().is_none());
+  let region: VolRegion<u32, Safe, Safe> = unsafe {
+    VolRegion::from_raw_parts(VolAddress::new(hw.as_mut_ptr() as usize), 4)
+  };
+  assert!(unsafe { RAM.set(region) }.is_ok());
+  assert!(unsafe { RAM.set(region) }.is_err());
+  let got = RAM.get().unwrap();
+  assert_eq!(got, region);
+  got.index(2).write(5);
+  assert_eq!(hw[2], 5);
+}
+
+#[test]
+fn test_oncevoladdress() {
+  let cell: OnceVolAddress<u8, Safe, ()> = OnceVolAddress::new();
+  assert!(cell.get().is_none());
+  let a = unsafe { VolAddress::new(4) };
+  assert!(unsafe { cell.set(a) }.is_ok());
+  assert_eq!(cell.get(), Some(a));
+}
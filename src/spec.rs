@@ -0,0 +1,196 @@
+use super::*;
+
+/// Describes a register type for the svd2rust style `modify` API.
+///
+/// You give each register type a reader type and a writer type, which have
+/// the accessor methods for each field of the register. Then
+/// [`modify`](VolAddress::modify) passes a reader of the current value and a
+/// writer to your closure, and writes back whatever the writer holds.
+///
+/// ```
+/// # use voladdress::*;
+/// #[derive(Clone, Copy)]
+/// #[repr(transparent)]
+/// pub struct Ctrl(u16);
+///
+/// pub struct CtrlR(u16);
+/// impl From<Ctrl> for CtrlR {
+///   fn from(c: Ctrl) -> Self {
+///     Self(c.0)
+///   }
+/// }
+/// impl CtrlR {
+///   pub fn enable(&self) -> bool {
+///     self.0 & 1 != 0
+///   }
+///   pub fn prescaler(&self) -> u16 {
+///     (self.0 >> 4) & 0b111
+///   }
+/// }
+///
+/// pub struct CtrlW(u16);
+/// impl From<Ctrl> for CtrlW {
+///   fn from(c: Ctrl) -> Self {
+///     Self(c.0)
+///   }
+/// }
+/// impl From<CtrlW> for Ctrl {
+///   fn from(w: CtrlW) -> Self {
+///     Self(w.0)
+///   }
+/// }
+/// impl CtrlW {
+///   pub fn enable(&mut self, b: bool) -> &mut Self {
+///     self.0 = (self.0 & !1) | u16::from(b);
+///     self
+///   }
+///   pub fn prescaler(&mut self, p: u16) -> &mut Self {
+///     self.0 = (self.0 & !(0b111 << 4)) | ((p & 0b111) << 4);
+///     self
+///   }
+/// }
+///
+/// impl RegisterSpec for Ctrl {
+///   type Reader = CtrlR;
+///   type Writer = CtrlW;
+/// }
+///
+/// let mut hw = Ctrl(0);
+/// let ctrl: VolAddress<Ctrl, Safe, Safe> =
+///   unsafe { VolAddress::new(&mut hw as *mut Ctrl as usize) };
+/// ctrl.modify(|_, w| w.enable(true).prescaler(3));
+/// ctrl.modify(|r, w| w.prescaler(r.prescaler() + 1));
+/// assert_eq!(hw.0, 0x0041);
+/// ```
+pub trait RegisterSpec: Copy {
+  /// Reads the fields of a register value.
+  type Reader: From<Self>;
+  /// Changes the fields of a register value.
+  type Writer: From<Self> + Into<Self>;
+}
+
+impl<T, A> VolAddress<T, Safe, Safe, A>
+where
+  T: RegisterSpec,
+{
+  /// Reads the register, lets the closure change fields with the writer, and
+  /// writes back the new value.
+  ///
+  /// The closure also gets a reader of the value that was read.
+  #[inline]
+  pub fn modify<F>(self, f: F)
+  where
+    F: for<'w> FnOnce(&T::Reader, &'w mut T::Writer) -> &'w mut T::Writer,
+  {
+    let t = self.read();
+    let r = T::Reader::from(t);
+    let mut w = T::Writer::from(t);
+    f(&r, &mut w);
+    self.write(w.into());
+  }
+}
+impl<T, R, W, A> VolAddress<T, R, W, A>
+where
+  T: RegisterSpec,
+  (R, W): UnsafeReadWrite,
+{
+  /// Reads the register, lets the closure change fields with the writer, and
+  /// writes back the new value.
+  ///
+  /// The closure also gets a reader of the value that was read.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading/writing this address depend on the device.
+  ///   Consult your hardware manual.
+  #[inline]
+  pub unsafe fn modify<F>(self, f: F)
+  where
+    F: for<'w> FnOnce(&T::Reader, &'w mut T::Writer) -> &'w mut T::Writer,
+  {
+    let t = self.read_raw();
+    let r = T::Reader::from(t);
+    let mut w = T::Writer::from(t);
+    f(&r, &mut w);
+    self.write_raw(w.into());
+  }
+}
+
+impl<T, R, A> VolAddress<T, R, Safe, A>
+where
+  T: RegisterSpec + ResetValue,
+{
+  /// Lets the closure set fields with a writer that starts at the reset value,
+  /// and then writes the result.
+  ///
+  /// The register isn't read, so any fields that the closure doesn't set will
+  /// go back to their reset values.
+  #[inline]
+  pub fn write_from_reset<F>(self, f: F)
+  where
+    F: FnOnce(&mut T::Writer) -> &mut T::Writer,
+  {
+    let mut w = T::Writer::from(T::RESET_VALUE);
+    f(&mut w);
+    self.write(w.into());
+  }
+}
+impl<T, R, A> VolAddress<T, R, Unsafe, A>
+where
+  T: RegisterSpec + ResetValue,
+{
+  /// Lets the closure set fields with a writer that starts at the reset value,
+  /// and then writes the result.
+  ///
+  /// The register isn't read, so any fields that the closure doesn't set will
+  /// go back to their reset values.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn write_from_reset<F>(self, f: F)
+  where
+    F: FnOnce(&mut T::Writer) -> &mut T::Writer,
+  {
+    let mut w = T::Writer::from(T::RESET_VALUE);
+    f(&mut w);
+    self.write(w.into());
+  }
+}
+
+#[test]
+fn test_register_spec() {
+  #[derive(Clone, Copy)]
+  struct Reg(u8);
+  struct RegW(u8);
+  impl From<Reg> for RegW {
+    fn from(r: Reg) -> Self {
+      Self(r.0)
+    }
+  }
+  impl From<RegW> for Reg {
+    fn from(w: RegW) -> Self {
+      Self(w.0)
+    }
+  }
+  impl RegW {
+    fn set(&mut self, bit: u8) -> &mut Self {
+      self.0 |= 1 << bit;
+      self
+    }
+  }
+  impl RegisterSpec for Reg {
+    type Reader = Reg;
+    type Writer = RegW;
+  }
+  impl ResetValue for Reg {
+    const RESET_VALUE: Self = Reg(0x80);
+  }
+  let mut hw = Reg(0b0001);
+  let a: VolAddress<Reg, Unsafe, Safe> =
+    unsafe { VolAddress::new(&mut hw as *mut Reg as usize) };
+  unsafe { a.modify(|r, w| w.set(r.0 + 1)) };
+  assert_eq!(hw.0, 0b0101);
+  a.write_from_reset(|w| w.set(0));
+  assert_eq!(hw.0, 0x81);
+}
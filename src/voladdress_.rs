@@ -26,6 +26,15 @@ use super::*;
 ///   * If `W=Safe` then you can safely write to the address.
 ///   * If `W=Unsafe` then you can unsafely write to the address.
 ///   * Otherwise you cannot write to the address.
+/// * `A`: A tag for the address space (or peripheral) that the address is in.
+///   This defaults to `()`, and has no effect on how the address is accessed.
+///   If you give each address space its own tag type then addresses from
+///   different spaces can't be compared or mixed up with each other. For
+///   example, the NDS has separate ARM7 and ARM9 views of its IO registers.
+///   Only `VolAddress` itself carries the tag. The other types in this crate
+///   (blocks, regions, series, their iterators, and so on) always give
+///   untagged addresses, as do constructors like `from_static_mut`. Use
+///   [`change_space`](VolAddress::change_space) to tag them.
 ///
 /// The `VolAddress` type is intended to represent a single value of a `T` type
 /// that is the size of a single machine register (or less).
@@ -44,7 +53,10 @@ use super::*;
 /// * **Safety Invariant**: The address of a `VolAddress` must be an aligned and
 ///   legal address for a `T` type value (with correct `R` and `W` permissions)
 ///   within the device's memory space, otherwise the `read` and `write` methods
-///   will trigger UB when called.
+///   will trigger UB when called. The `read_unaligned` and `write_unaligned`
+///   methods don't need the address to be aligned, but if an address might not
+///   be aligned you should give it `Unsafe` permissions so that the normal
+///   `read` and `write` methods can't be safely called.
 /// * **Synchronization Invariant**: Volatile access has **no** cross-thread
 ///   synchronization behavior within the LLVM memory model. The results of
 ///   *all* volatile access is target-dependent, including cross-thread access.
@@ -54,23 +66,24 @@ use super::*;
 ///   in some way external to this type.
 #[repr(transparent)]
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct VolAddress<T, R, W> {
+pub struct VolAddress<T, R, W, A = ()> {
   pub(crate) address: NonZeroUsize,
   target: PhantomData<T>,
   read_status: PhantomData<R>,
   write_status: PhantomData<W>,
+  space: PhantomData<A>,
 }
 
-impl<T, R, W> Clone for VolAddress<T, R, W> {
+impl<T, R, W, A> Clone for VolAddress<T, R, W, A> {
   #[inline]
   #[must_use]
   fn clone(&self) -> Self {
     *self
   }
 }
-impl<T, R, W> Copy for VolAddress<T, R, W> {}
+impl<T, R, W, A> Copy for VolAddress<T, R, W, A> {}
 
-impl<T, R, W> VolAddress<T, R, W> {
+impl<T, R, W, A> VolAddress<T, R, W, A> {
   /// Constructs the value.
   ///
   /// ## Safety
@@ -83,6 +96,44 @@ impl<T, R, W> VolAddress<T, R, W> {
       target: PhantomData,
       read_status: PhantomData,
       write_status: PhantomData,
+      space: PhantomData,
+    }
+  }
+
+  /// Constructs the value, checking that the address is non-zero and aligned.
+  ///
+  /// When used to initialize a `const` this check happens at compile time, so
+  /// a typo'd address will fail the build.
+  ///
+  /// ## Panics
+  /// * If the address is zero or isn't aligned for `T`.
+  ///
+  /// ## Safety
+  /// * As per the type docs, other than the address being non-zero and
+  ///   aligned.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub const unsafe fn new_checked(address: usize) -> Self {
+    assert!(address != 0);
+    assert!(address & (core::mem::align_of::<T>() - 1) == 0);
+    Self::new(address)
+  }
+
+  /// Constructs the value, or gives `None` if the address is zero.
+  ///
+  /// This is useful when the address is determined at runtime (eg: read from a
+  /// device tree or a PCI BAR), and so it might be zero.
+  ///
+  /// ## Safety
+  /// * As per the type docs, other than the address being non-zero.
+  #[inline]
+  #[must_use]
+  pub const unsafe fn try_new(address: usize) -> Option<Self> {
+    if address != 0 {
+      Some(Self::new(address))
+    } else {
+      None
     }
   }
 
@@ -92,12 +143,13 @@ impl<T, R, W> VolAddress<T, R, W> {
   /// * As per the type docs
   #[inline]
   #[must_use]
-  pub const unsafe fn cast<Z>(self) -> VolAddress<Z, R, W> {
+  pub const unsafe fn cast<Z>(self) -> VolAddress<Z, R, W, A> {
     VolAddress {
       address: self.address,
       target: PhantomData,
       read_status: PhantomData,
       write_status: PhantomData,
+      space: PhantomData,
     }
   }
 
@@ -110,15 +162,99 @@ impl<T, R, W> VolAddress<T, R, W> {
   #[must_use]
   pub const unsafe fn change_permissions<NewRead, NewWrite>(
     self,
-  ) -> VolAddress<T, NewRead, NewWrite> {
+  ) -> VolAddress<T, NewRead, NewWrite, A> {
     VolAddress {
       address: self.address,
       target: PhantomData,
       read_status: PhantomData,
       write_status: PhantomData,
+      space: PhantomData,
     }
   }
 
+  /// Upgrades the read permission to `Safe`, using a proof that addresses in
+  /// this address space are safe to read.
+  #[inline]
+  #[must_use]
+  pub fn upgrade_read_with<P: ReadProof<A>>(
+    self, _proof: &P,
+  ) -> VolAddress<T, Safe, W, A> {
+    // Safety: the proof type asserts that this address is safe to read.
+    unsafe { self.change_permissions() }
+  }
+
+  /// Upgrades the write permission to `Safe`, using a proof that addresses in
+  /// this address space are safe to write.
+  #[inline]
+  #[must_use]
+  pub fn upgrade_write_with<P: WriteProof<A>>(
+    self, _proof: &P,
+  ) -> VolAddress<T, R, Safe, A> {
+    // Safety: the proof type asserts that this address is safe to write.
+    unsafe { self.change_permissions() }
+  }
+
+  /// Changes the address space tag from `A` to `B`.
+  ///
+  /// ## Safety
+  /// * As per the type docs
+  #[inline]
+  #[must_use]
+  pub const unsafe fn change_space<B>(self) -> VolAddress<T, R, W, B> {
+    VolAddress {
+      address: self.address,
+      target: PhantomData,
+      read_status: PhantomData,
+      write_status: PhantomData,
+      space: PhantomData,
+    }
+  }
+
+  /// Removes the write permission, giving a read-only address.
+  ///
+  /// This is always safe, because removing a capability can't give anyone new
+  /// power.
+  #[inline]
+  #[must_use]
+  pub const fn as_readonly(self) -> VolAddress<T, R, (), A> {
+    unsafe { self.change_permissions() }
+  }
+
+  /// Removes the read permission, giving a write-only address.
+  ///
+  /// This is always safe, because removing a capability can't give anyone new
+  /// power.
+  #[inline]
+  #[must_use]
+  pub const fn as_writeonly(self) -> VolAddress<T, (), W, A> {
+    unsafe { self.change_permissions() }
+  }
+
+  /// Changes the target type from `T` to `Z`, for plain data types.
+  ///
+  /// Because both types are [`Pod`](bytemuck::Pod), any value of one type is
+  /// a valid value of the other, so unlike `cast` this is safe. This is useful
+  /// for things like viewing a framebuffer of `u16` as `[u8; 2]` instead.
+  ///
+  /// ## Panics
+  /// * If `Z` isn't the same size as `T`.
+  /// * If `Z` has a larger alignment than `T`.
+  #[cfg(feature = "bytemuck")]
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub fn cast_pod<Z>(self) -> VolAddress<Z, R, W, A>
+  where
+    T: bytemuck::Pod,
+    Z: bytemuck::Pod,
+  {
+    assert!(core::mem::size_of::<Z>() == core::mem::size_of::<T>());
+    assert!(core::mem::align_of::<Z>() <= core::mem::align_of::<T>());
+    // Safety: the types are the same size and the address is aligned for `Z`,
+    // and any bit pattern is valid for both types.
+    unsafe { self.cast() }
+  }
+
   /// Converts the `VolAddress` back into a normal `usize` value.
   #[inline]
   #[must_use]
@@ -153,10 +289,14 @@ impl<T, R, W> VolAddress<T, R, W> {
   /// This is intended to basically work like [`<*mut
   /// T>::wrapping_add`](https://doc.rust-lang.org/std/primitive.pointer.html#method.wrapping_add-1).
   ///
+  /// ## Panics
+  /// * As per `offset`, when debug assertions are enabled.
+  ///
   /// ## Safety
   /// * As per the type docs
   #[inline]
   #[must_use]
+  #[track_caller]
   pub const unsafe fn add(self, count: usize) -> Self {
     self.offset(count as isize)
   }
@@ -168,10 +308,14 @@ impl<T, R, W> VolAddress<T, R, W> {
   /// This is intended to basically work like [`<*mut
   /// T>::wrapping_sub`](https://doc.rust-lang.org/std/primitive.pointer.html#method.wrapping_sub-1).
   ///
+  /// ## Panics
+  /// * As per `offset`, when debug assertions are enabled.
+  ///
   /// ## Safety
   /// * As per the type docs
   #[inline]
   #[must_use]
+  #[track_caller]
   pub const unsafe fn sub(self, count: usize) -> Self {
     self.offset((count as isize).wrapping_neg())
   }
@@ -181,11 +325,29 @@ impl<T, R, W> VolAddress<T, R, W> {
   /// This is intended to basically work like [`<*mut
   /// T>::wrapping_offset`](https://doc.rust-lang.org/std/primitive.pointer.html#method.wrapping_offset-1).
   ///
+  /// ## Panics
+  /// * When debug assertions are enabled, this will panic if the new address
+  ///   would wrap around the address space or be zero. Either of those means
+  ///   that the math which computed `count` has a bug.
+  ///
   /// ## Safety
   /// * As per the type docs
   #[inline]
   #[must_use]
+  #[track_caller]
   pub const unsafe fn offset(self, count: isize) -> Self {
+    if cfg!(debug_assertions) {
+      let unwrapped =
+        match core::mem::size_of::<T>().checked_mul(count.unsigned_abs()) {
+          Some(delta) if count < 0 => self.address.get().checked_sub(delta),
+          Some(delta) => self.address.get().checked_add(delta),
+          None => None,
+        };
+      assert!(
+        matches!(unwrapped, Some(a) if a != 0),
+        "VolAddress offset wrapped around the address space or became zero"
+      );
+    }
     let total_delta = core::mem::size_of::<T>().wrapping_mul(count as usize);
     VolAddress {
       address: NonZeroUsize::new_unchecked(
@@ -194,10 +356,123 @@ impl<T, R, W> VolAddress<T, R, W> {
       target: PhantomData,
       read_status: PhantomData,
       write_status: PhantomData,
+      space: PhantomData,
     }
   }
 }
 
+impl<T, W, A> VolAddress<T, Safe, W, A> {
+  /// Weakens a `Safe` read permission to `Unsafe`.
+  #[inline]
+  #[must_use]
+  pub const fn weaken_read_to_unsafe(self) -> VolAddress<T, Unsafe, W, A> {
+    unsafe { self.change_permissions() }
+  }
+}
+
+impl<T, R, A> VolAddress<T, R, Safe, A> {
+  /// Weakens a `Safe` write permission to `Unsafe`.
+  #[inline]
+  #[must_use]
+  pub const fn weaken_write_to_unsafe(self) -> VolAddress<T, R, Unsafe, A> {
+    unsafe { self.change_permissions() }
+  }
+}
+
+impl<T, A> VolAddress<T, Safe, Safe, A> {
+  /// Weakens both `Safe` permissions to `Unsafe`.
+  #[inline]
+  #[must_use]
+  pub const fn weaken_to_unsafe(self) -> VolAddress<T, Unsafe, Unsafe, A> {
+    unsafe { self.change_permissions() }
+  }
+}
+
+impl<T> VolAddress<T, Safe, Safe> {
+  /// Constructs the value from an exclusive static reference.
+  ///
+  /// This is safe because a `&'static mut T` is always non-null, aligned, and
+  /// valid to read and write for the rest of the program. Since the reference
+  /// is consumed, no other code can observe the memory through a normal
+  /// reference.
+  ///
+  /// This is mostly useful for making volatile views of buffers in RAM, such
+  /// as a DMA buffer placed in `.bss`. The address is untagged, use
+  /// [`change_space`](VolAddress::change_space) if you need a tag.
+  #[inline]
+  #[must_use]
+  pub fn from_static_mut(r: &'static mut T) -> Self {
+    // Safety: references are always non-null, aligned, and valid for reads and
+    // writes of `T`, and a `'static` reference will stay valid forever.
+    unsafe { Self::new(r as *mut T as usize) }
+  }
+}
+
+#[test]
+fn test_voladdress_new_checked() {
+  const A: VolAddress<u32, (), ()> = unsafe { VolAddress::new_checked(4) };
+  assert_eq!(A.as_usize(), 4);
+}
+
+#[test]
+#[should_panic]
+fn test_voladdress_new_checked_zero_panic() {
+  let _a: VolAddress<u32, (), ()> = unsafe { VolAddress::new_checked(0) };
+}
+
+#[test]
+#[should_panic]
+fn test_voladdress_new_checked_misaligned_panic() {
+  let _a: VolAddress<u32, (), ()> = unsafe { VolAddress::new_checked(2) };
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn test_voladdress_offset_to_zero_panic() {
+  let a: VolAddress<u16, (), ()> = unsafe { VolAddress::new(2) };
+  let _a = unsafe { a.sub(1) };
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn test_voladdress_offset_wrap_panic() {
+  let a: VolAddress<u16, (), ()> = unsafe { VolAddress::new(usize::MAX - 1) };
+  let _a = unsafe { a.add(1) };
+}
+
+#[test]
+fn test_voladdress_weaken_permissions() {
+  let a: VolAddress<u8, Safe, Safe> = unsafe { VolAddress::new(1) };
+  let _: VolAddress<u8, Safe, ()> = a.as_readonly();
+  let _: VolAddress<u8, (), Safe> = a.as_writeonly();
+  let _: VolAddress<u8, Unsafe, Safe> = a.weaken_read_to_unsafe();
+  let _: VolAddress<u8, Safe, Unsafe> = a.weaken_write_to_unsafe();
+  let u: VolAddress<u8, Unsafe, Unsafe> = a.weaken_to_unsafe();
+  assert_eq!(u.as_usize(), 1);
+}
+
+#[test]
+fn test_voladdress_try_new() {
+  let a: Option<VolAddress<u8, (), ()>> = unsafe { VolAddress::try_new(0) };
+  assert!(a.is_none());
+  let a: Option<VolAddress<u8, (), ()>> = unsafe { VolAddress::try_new(4) };
+  assert_eq!(a.unwrap().as_usize(), 4);
+}
+
+#[test]
+fn test_voladdress_from_static_mut() {
+  extern crate std;
+  let r: &'static mut u32 = std::boxed::Box::leak(std::boxed::Box::new(5));
+  let p = r as *mut u32 as usize;
+  let a = VolAddress::from_static_mut(r);
+  assert_eq!(a.as_usize(), p);
+  assert_eq!(a.read(), 5);
+  a.write(7);
+  assert_eq!(a.read(), 7);
+}
+
 impl<T, R, W, const C: usize> VolAddress<[T; C], R, W> {
   /// Converts an address for an array to a block for each element of the array.
   ///
@@ -206,6 +481,10 @@ impl<T, R, W, const C: usize> VolAddress<[T; C], R, W> {
   /// * It is *highly likely* that on any device this is safe, but because of
   ///   possible strangeness with volatile side effects this is marked as an
   ///   `unsafe` method.
+  ///
+  /// Blocks don't carry an address space tag, so this is only available on
+  /// untagged addresses. Use [`change_space`](VolAddress::change_space) to
+  /// remove a tag first.
   #[inline]
   #[must_use]
   pub const unsafe fn as_volblock(self) -> VolBlock<T, R, W, C> {
@@ -213,7 +492,93 @@ impl<T, R, W, const C: usize> VolAddress<[T; C], R, W> {
   }
 }
 
-impl<T, W> VolAddress<T, Safe, W>
+impl<T, R, W, A> VolAddress<T, R, W, A>
+where
+  T: Copy,
+{
+  /// The volatile read used by every read method, whatever the permission.
+  ///
+  /// ## Safety
+  /// * The caller must have the permission to read the address.
+  #[inline]
+  pub(crate) unsafe fn read_raw(self) -> T {
+    let t = read_volatile(self.address.get() as *const T);
+    #[cfg(feature = "journal")]
+    crate::journal::record::<T>(self.address.get(), false);
+    t
+  }
+
+  /// The volatile write used by every write method, whatever the permission.
+  ///
+  /// ## Safety
+  /// * The caller must have the permission to write the address.
+  #[inline]
+  pub(crate) unsafe fn write_raw(self, t: T) {
+    #[cfg(feature = "journal")]
+    crate::journal::record::<T>(self.address.get(), true);
+    write_volatile(self.address.get() as *mut T, t)
+  }
+
+  /// The read used by every `read_into` method, whatever the permission.
+  ///
+  /// ## Safety
+  /// * The caller must have the permission to read the address.
+  #[inline]
+  pub(crate) unsafe fn read_into_raw(self, dst: &mut MaybeUninit<T>) -> &mut T {
+    if core::mem::size_of::<T>() <= core::mem::size_of::<usize>() {
+      return dst.write(self.read_raw());
+    }
+    // The size of `T` is a multiple of its alignment, and so also a multiple
+    // of any smaller power of two.
+    match core::mem::align_of::<T>().min(core::mem::size_of::<usize>()) {
+      8 => self.read_words_into::<u64>(dst),
+      4 => self.read_words_into::<u32>(dst),
+      2 => self.read_words_into::<u16>(dst),
+      _ => self.read_words_into::<u8>(dst),
+    }
+    dst.assume_init_mut()
+  }
+
+  /// Volatile reads each `U` sized word of the value into `dst`.
+  ///
+  /// ## Safety
+  /// * The caller must have the permission to read the address.
+  /// * `U` must be an integer type whose size divides the size of `T`, and
+  ///   whose alignment is at most the alignment of `T`.
+  #[inline]
+  unsafe fn read_words_into<U: Copy>(self, dst: &mut MaybeUninit<T>) {
+    let src = self.cast::<U>();
+    let p = dst.as_mut_ptr() as *mut U;
+    for i in 0..core::mem::size_of::<T>() / core::mem::size_of::<U>() {
+      p.add(i).write(src.add(i).read_raw());
+    }
+  }
+
+  /// The read-modify-write used by every `apply` method, whatever the
+  /// permission.
+  ///
+  /// ## Safety
+  /// * The caller must have the permission to read and write the address.
+  #[inline]
+  pub(crate) unsafe fn apply_raw<F: FnOnce(&mut T)>(self, op: F) {
+    let mut temp = self.read_raw();
+    op(&mut temp);
+    self.write_raw(temp);
+  }
+
+  /// The exchange used by every `exchange` method, whatever the permission.
+  ///
+  /// ## Safety
+  /// * The caller must have the permission to read and write the address.
+  #[inline]
+  pub(crate) unsafe fn exchange_raw(self, new: T) -> T {
+    let old = self.read_raw();
+    self.write_raw(new);
+    old
+  }
+}
+
+impl<T, W, A> VolAddress<T, Safe, W, A>
 where
   T: Copy,
 {
@@ -222,10 +587,78 @@ where
   pub fn read(self) -> T {
     // Safety: The declarer of the value gave this a `Safe` read typing, thus
     // they've asserted that this is a safe to read address.
-    unsafe { read_volatile(self.address.get() as *const T) }
+    unsafe { self.read_raw() }
+  }
+
+  /// Volatile reads the current value of `A`, then discards it.
+  ///
+  /// This is for registers where the read itself has an effect, such as a
+  /// status register that clears its flags when read.
+  #[inline]
+  pub fn read_discard(self) {
+    let _ = self.read();
+  }
+
+  /// Volatile reads `A` once for each element of the slice, in order.
+  ///
+  /// Every read is from the *same* address. This is for FIFO or data port
+  /// registers, where each read pops another value from the hardware.
+  #[inline]
+  pub fn read_all_into_slice(self, buffer: &mut [T]) {
+    buffer.iter_mut().for_each(|t| *t = self.read())
+  }
+
+  /// Volatile reads the current value of `A`, followed by an acquire fence.
+  ///
+  /// No memory access that comes after this read in program order can be
+  /// moved to before it. Use this when reading a register tells you that
+  /// some other memory (such as a buffer written by DMA) is ready to be read.
+  #[inline]
+  pub fn read_acquire(self) -> T {
+    let t = self.read();
+    fence(Ordering::Acquire);
+    t
+  }
+
+  /// Volatile reads the current value of `A`, one byte at a time.
+  ///
+  /// The bytes are read in order of increasing address. This doesn't require
+  /// the address to be aligned for `T`.
+  #[inline]
+  pub fn read_unaligned(self) -> T {
+    // Safety: The declarer of the value gave this a `Safe` read typing, thus
+    // they've asserted that this is a safe to read address.
+    unsafe { read_volatile_bytewise(self.address.get()) }
+  }
+
+  /// Volatile reads the current value of `A` into the uninitialized storage
+  /// given.
+  ///
+  /// This lets a large value be read directly into its final location, without
+  /// a temporary copy on the stack. A reference to the now initialized value
+  /// is returned.
+  ///
+  /// If `T` fits in a `usize` this is a single volatile read, the same as
+  /// `read`. Otherwise the value is read in words (as wide as the alignment of
+  /// `T` allows, up to a `usize`) in order of increasing address, and each
+  /// word is written straight into `dst`.
+  #[inline]
+  pub fn read_into(self, dst: &mut MaybeUninit<T>) -> &mut T {
+    // Safety: The declarer of the value gave this a `Safe` read typing, thus
+    // they've asserted that this is a safe to read address.
+    unsafe { self.read_into_raw(dst) }
+  }
+
+  /// Volatile reads `A`, and checks if it holds the reset value of `T`.
+  #[inline]
+  pub fn is_at_reset(self) -> bool
+  where
+    T: ResetValue + PartialEq,
+  {
+    self.read() == T::RESET_VALUE
   }
 }
-impl<T, W> VolAddress<T, Unsafe, W>
+impl<T, W, A> VolAddress<T, Unsafe, W, A>
 where
   T: Copy,
 {
@@ -236,11 +669,99 @@ where
   ///   your hardware manual.
   #[inline]
   pub unsafe fn read(self) -> T {
-    read_volatile(self.address.get() as *const T)
+    self.read_raw()
+  }
+
+  /// Volatile reads the current value of `A`, then discards it.
+  ///
+  /// This is for registers where the read itself has an effect, such as a
+  /// status register that clears its flags when read.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn read_discard(self) {
+    let _ = self.read();
+  }
+
+  /// Volatile reads `A` once for each element of the slice, in order.
+  ///
+  /// Every read is from the *same* address. This is for FIFO or data port
+  /// registers, where each read pops another value from the hardware.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn read_all_into_slice(self, buffer: &mut [T]) {
+    buffer.iter_mut().for_each(|t| *t = self.read())
+  }
+
+  /// Volatile reads the current value of `A`, followed by an acquire fence.
+  ///
+  /// No memory access that comes after this read in program order can be
+  /// moved to before it. Use this when reading a register tells you that
+  /// some other memory (such as a buffer written by DMA) is ready to be read.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn read_acquire(self) -> T {
+    let t = self.read();
+    fence(Ordering::Acquire);
+    t
+  }
+
+  /// Volatile reads the current value of `A`, one byte at a time.
+  ///
+  /// The bytes are read in order of increasing address. This doesn't require
+  /// the address to be aligned for `T`.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn read_unaligned(self) -> T {
+    read_volatile_bytewise(self.address.get())
+  }
+
+  /// Volatile reads the current value of `A` into the uninitialized storage
+  /// given.
+  ///
+  /// This lets a large value be read directly into its final location, without
+  /// a temporary copy on the stack. A reference to the now initialized value
+  /// is returned.
+  ///
+  /// If `T` fits in a `usize` this is a single volatile read, the same as
+  /// `read`. Otherwise the value is read in words (as wide as the alignment of
+  /// `T` allows, up to a `usize`) in order of increasing address, and each
+  /// word is written straight into `dst`.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn read_into(self, dst: &mut MaybeUninit<T>) -> &mut T {
+    self.read_into_raw(dst)
+  }
+
+  /// Volatile reads `A`, and checks if it holds the reset value of `T`.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn is_at_reset(self) -> bool
+  where
+    T: ResetValue + PartialEq,
+  {
+    self.read() == T::RESET_VALUE
   }
 }
 
-impl<T, R> VolAddress<T, R, Safe>
+impl<T, R, A> VolAddress<T, R, Safe, A>
 where
   T: Copy,
 {
@@ -249,10 +770,69 @@ where
   pub fn write(self, t: T) {
     // Safety: The declarer of the value gave this a `Safe` write typing, thus
     // they've asserted that this is a safe to write address.
-    unsafe { write_volatile(self.address.get() as *mut T, t) }
+    unsafe { self.write_raw(t) }
+  }
+
+  /// Volatile writes a new value to `A`, one byte at a time.
+  ///
+  /// The bytes are written in order of increasing address. This doesn't
+  /// require the address to be aligned for `T`.
+  #[inline]
+  pub fn write_unaligned(self, t: T) {
+    // Safety: The declarer of the value gave this a `Safe` write typing, thus
+    // they've asserted that this is a safe to write address.
+    unsafe { write_volatile_bytewise(self.address.get(), t) }
+  }
+
+  /// Volatile writes every element of the slice to `A`, in order.
+  ///
+  /// Every write goes to the *same* address. This is for FIFO or data port
+  /// registers, where each write pushes another value into the hardware.
+  #[inline]
+  pub fn write_all_from_slice(self, buffer: &[T]) {
+    buffer.iter().for_each(|t| self.write(*t))
+  }
+
+  /// Volatile writes a new value to `A`, preceded by a release fence.
+  ///
+  /// No memory access that comes before this write in program order can be
+  /// moved to after it. Use this when writing a register hands some other
+  /// memory (such as a DMA descriptor) over to the hardware.
+  #[inline]
+  pub fn write_release(self, t: T) {
+    fence(Ordering::Release);
+    self.write(t)
+  }
+
+  /// Volatile writes the default value of `T` to `A`.
+  #[inline]
+  pub fn write_default(self)
+  where
+    T: Default,
+  {
+    self.write(T::default())
+  }
+
+  /// Volatile writes the all-zeroes value of `T` to `A`.
+  #[cfg(feature = "bytemuck")]
+  #[inline]
+  pub fn write_zeroed(self)
+  where
+    T: bytemuck::Zeroable,
+  {
+    self.write(T::zeroed())
+  }
+
+  /// Volatile writes the reset value of `T` to `A`.
+  #[inline]
+  pub fn reset(self)
+  where
+    T: ResetValue,
+  {
+    self.write(T::RESET_VALUE)
   }
 }
-impl<T, R> VolAddress<T, R, Unsafe>
+impl<T, R, A> VolAddress<T, R, Unsafe, A>
 where
   T: Copy,
 {
@@ -263,57 +843,410 @@ where
   ///   your hardware manual.
   #[inline]
   pub unsafe fn write(self, t: T) {
-    write_volatile(self.address.get() as *mut T, t)
+    self.write_raw(t)
+  }
+
+  /// Volatile writes a new value to `A`, one byte at a time.
+  ///
+  /// The bytes are written in order of increasing address. This doesn't
+  /// require the address to be aligned for `T`.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn write_unaligned(self, t: T) {
+    write_volatile_bytewise(self.address.get(), t)
+  }
+
+  /// Volatile writes every element of the slice to `A`, in order.
+  ///
+  /// Every write goes to the *same* address. This is for FIFO or data port
+  /// registers, where each write pushes another value into the hardware.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn write_all_from_slice(self, buffer: &[T]) {
+    buffer.iter().for_each(|t| self.write(*t))
+  }
+
+  /// Volatile writes a new value to `A`, preceded by a release fence.
+  ///
+  /// No memory access that comes before this write in program order can be
+  /// moved to after it. Use this when writing a register hands some other
+  /// memory (such as a DMA descriptor) over to the hardware.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn write_release(self, t: T) {
+    fence(Ordering::Release);
+    self.write(t)
+  }
+
+  /// Volatile writes the default value of `T` to `A`.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn write_default(self)
+  where
+    T: Default,
+  {
+    self.write(T::default())
+  }
+
+  /// Volatile writes the all-zeroes value of `T` to `A`.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[cfg(feature = "bytemuck")]
+  #[inline]
+  pub unsafe fn write_zeroed(self)
+  where
+    T: bytemuck::Zeroable,
+  {
+    self.write(T::zeroed())
+  }
+
+  /// Volatile writes the reset value of `T` to `A`.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn reset(self)
+  where
+    T: ResetValue,
+  {
+    self.write(T::RESET_VALUE)
   }
 }
 
-impl<T> VolAddress<T, Safe, Safe>
+impl<T, W, A, const N: usize> VolAddress<T, DummyReads<N>, W, A>
 where
   T: Copy,
 {
-  /// Reads the address, applies the operation, and writes back the new value.
+  /// Volatile reads the address `N` times, discarding each value, and then
+  /// volatile reads it once more to get the current value.
   #[inline]
-  pub fn apply<F: FnOnce(&mut T)>(self, op: F) {
-    let mut temp = self.read();
-    op(&mut temp);
-    self.write(temp);
+  pub fn read(self) -> T {
+    // Safety: The declarer of the value gave this a `DummyReads` read typing,
+    // thus they've asserted that this is a safe to read address.
+    unsafe {
+      for _ in 0..N {
+        let _ = self.read_raw();
+      }
+      self.read_raw()
+    }
   }
 }
-impl<T> VolAddress<T, Unsafe, Safe>
+
+impl<T, W, A> VolAddress<T, RC, W, A>
 where
   T: Copy,
 {
-  /// Reads the address, applies the operation, and writes back the new value.
+  /// Volatile reads the current value, which also clears it in hardware.
   ///
-  /// ## Safety
-  /// * The safety rules of reading/writing this address depend on the device.
-  ///   Consult your hardware manual.
+  /// The value is lost if you don't use it, so this is `#[must_use]`.
   #[inline]
-  pub unsafe fn apply<F: FnOnce(&mut T)>(self, op: F) {
-    let mut temp = self.read();
-    op(&mut temp);
-    self.write(temp);
+  #[must_use]
+  pub fn read_and_clear(self) -> T {
+    // Safety: The declarer of the value gave this a `RC` read typing, thus
+    // they've asserted that this is a safe to read address.
+    unsafe { self.read_raw() }
+  }
+}
+
+impl<T, R, K, A> VolAddress<T, R, WriteOnce<K>, A>
+where
+  T: Copy,
+{
+  /// Volatile writes a new value to `A`, using up the token.
+  #[inline]
+  pub fn write_once(self, token: WriteOnceToken<K>, t: T) {
+    let _ = token;
+    // Safety: The declarer of the value gave this a `WriteOnce` write typing,
+    // thus they've asserted that this is a safe to write address, and the
+    // token shows that it hasn't been written before.
+    unsafe { self.write_raw(t) }
   }
 }
-impl<T> VolAddress<T, Safe, Unsafe>
+
+impl<T, R, A> VolAddress<T, R, W1C, A>
+where
+  T: Copy,
+{
+  /// Clears the bits set in `bits`, by writing `bits` to the address.
+  ///
+  /// This is a single volatile write, without reading the address first, so
+  /// only the bits given are cleared.
+  #[inline]
+  pub fn clear(self, bits: T) {
+    // Safety: The declarer of the value gave this a `W1C` write typing, thus
+    // they've asserted that writing a mask to this address is safe.
+    unsafe { self.write_raw(bits) }
+  }
+}
+
+/// Volatile reads a `T` one byte at a time, in order of increasing address.
+#[inline]
+unsafe fn read_volatile_bytewise<T: Copy>(address: usize) -> T {
+  let mut out = core::mem::MaybeUninit::<T>::uninit();
+  let dst = out.as_mut_ptr() as *mut u8;
+  for i in 0..core::mem::size_of::<T>() {
+    dst.add(i).write(read_volatile((address + i) as *const u8));
+  }
+  out.assume_init()
+}
+
+/// Volatile writes a `T` one byte at a time, in order of increasing address.
+#[inline]
+unsafe fn write_volatile_bytewise<T: Copy>(address: usize, t: T) {
+  let src = &t as *const T as *const u8;
+  for i in 0..core::mem::size_of::<T>() {
+    write_volatile((address + i) as *mut u8, src.add(i).read());
+  }
+}
+
+#[test]
+fn test_voladdress_write_default_and_reset() {
+  #[derive(Clone, Copy)]
+  struct Ctrl(u16);
+  impl ResetValue for Ctrl {
+    const RESET_VALUE: Self = Ctrl(0x80);
+  }
+  let mut hw = 5_u16;
+  let a: VolAddress<u16, Safe, Safe> =
+    unsafe { VolAddress::new(&mut hw as *mut u16 as usize) };
+  a.write_default();
+  assert_eq!(a.read(), 0);
+  let c: VolAddress<Ctrl, Safe, Safe> = unsafe { a.cast() };
+  c.reset();
+  assert_eq!(c.read().0, 0x80);
+}
+
+#[test]
+#[cfg(feature = "bytemuck")]
+fn test_voladdress_write_zeroed() {
+  let mut hw = [5_u16; 2];
+  let a: VolAddress<[u16; 2], Safe, Safe> =
+    unsafe { VolAddress::new(hw.as_mut_ptr() as usize) };
+  a.write_zeroed();
+  assert_eq!(a.read(), [0, 0]);
+}
+
+#[test]
+fn test_voladdress_write_all_from_slice() {
+  let mut hw = 0_u8;
+  let a: VolAddress<u8, Safe, Safe> =
+    unsafe { VolAddress::new(&mut hw as *mut u8 as usize) };
+  a.write_all_from_slice(&[1, 2, 3]);
+  assert_eq!(a.read(), 3);
+}
+
+#[test]
+fn test_voladdress_read_all_into_slice() {
+  let mut hw = 7_u8;
+  let a: VolAddress<u8, Safe, ()> =
+    unsafe { VolAddress::new(&mut hw as *mut u8 as usize) };
+  let mut buffer = [0; 3];
+  a.read_all_into_slice(&mut buffer);
+  assert_eq!(buffer, [7, 7, 7]);
+}
+
+#[test]
+fn test_voladdress_acquire_release() {
+  let mut hw = 0_u32;
+  let a: VolAddress<u32, Safe, Safe> =
+    unsafe { VolAddress::new(&mut hw as *mut u32 as usize) };
+  a.write_release(5);
+  assert_eq!(a.read_acquire(), 5);
+}
+
+#[test]
+fn test_voladdress_space_tag() {
+  struct Arm7;
+  let mut hw = 0_u8;
+  let a: VolAddress<u8, Safe, Safe, Arm7> =
+    unsafe { VolAddress::new(&mut hw as *mut u8 as usize) };
+  unsafe { a.add(0) }.write(3);
+  assert_eq!(a.read(), 3);
+  let b: VolAddress<u8, Safe, Safe> = unsafe { a.change_space() };
+  assert_eq!(b.read(), 3);
+  extern crate std;
+  use std::format;
+  assert!(format!("{:?}", a).contains("Arm7>("));
+  assert!(!format!("{:?}", b).contains("Arm7"));
+}
+
+#[test]
+#[cfg(feature = "bytemuck")]
+fn test_voladdress_cast_pod() {
+  let mut hw = 0x1234_u16;
+  let a: VolAddress<u16, Safe, Safe> =
+    unsafe { VolAddress::new(&mut hw as *mut u16 as usize) };
+  let b: VolAddress<[u8; 2], Safe, Safe> = a.cast_pod();
+  assert_eq!(b.read(), 0x1234_u16.to_ne_bytes());
+}
+
+#[test]
+#[cfg(feature = "bytemuck")]
+#[should_panic]
+fn test_voladdress_cast_pod_size_panic() {
+  let a: VolAddress<u16, Safe, Safe> = unsafe { VolAddress::new(2) };
+  let _b: VolAddress<u32, Safe, Safe> = a.cast_pod();
+}
+
+#[test]
+fn test_voladdress_upgrade_with() {
+  struct Trusted;
+  struct TrustedProof(());
+  unsafe impl ReadProof<Trusted> for TrustedProof {}
+  unsafe impl WriteProof<Trusted> for TrustedProof {}
+  let mut hw = 0_u8;
+  let a: VolAddress<u8, (), (), Trusted> =
+    unsafe { VolAddress::new(&mut hw as *mut u8 as usize) };
+  let proof = TrustedProof(());
+  let a = a.upgrade_write_with(&proof);
+  a.write(7);
+  assert_eq!(a.upgrade_read_with(&proof).read(), 7);
+}
+
+#[test]
+fn test_voladdress_exchange() {
+  let mut hw = 5_u16;
+  let a: VolAddress<u16, Safe, Safe> =
+    unsafe { VolAddress::new(&mut hw as *mut u16 as usize) };
+  assert_eq!(a.exchange(6), 5);
+  assert_eq!(a.read(), 6);
+}
+
+#[test]
+fn test_voladdress_read_into() {
+  let mut hw = [1_u32, 2, 3, 4];
+  let a: VolAddress<[u32; 4], Safe, ()> =
+    unsafe { VolAddress::new(hw.as_mut_ptr() as usize) };
+  let mut dst = MaybeUninit::uninit();
+  assert_eq!(a.read_into(&mut dst), &[1, 2, 3, 4]);
+  // Byte aligned values are read a byte at a time.
+  let mut hw = [1_u8, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+  let b: VolAddress<[u8; 11], Safe, ()> =
+    unsafe { VolAddress::new(hw.as_mut_ptr() as usize + 1) };
+  let mut dst = MaybeUninit::uninit();
+  assert_eq!(b.read_into(&mut dst), &[2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]);
+  // Small values are a single read.
+  let mut hw = 7_u16;
+  let c: VolAddress<u16, Safe, ()> =
+    unsafe { VolAddress::new(&mut hw as *mut u16 as usize) };
+  let mut dst = MaybeUninit::uninit();
+  assert_eq!(*c.read_into(&mut dst), 7);
+}
+
+#[test]
+fn test_voladdress_unaligned() {
+  let mut hw = [0_u8; 8];
+  let a: VolAddress<u32, Unsafe, Unsafe> =
+    unsafe { VolAddress::new(hw.as_mut_ptr() as usize + 1) };
+  unsafe { a.write_unaligned(0x1234_5678) };
+  assert_eq!(unsafe { a.read_unaligned() }, 0x1234_5678);
+  assert_eq!(&hw[1..5], &0x1234_5678_u32.to_ne_bytes());
+}
+
+#[test]
+fn test_voladdress_clear() {
+  let mut hw = 0b1010_u16;
+  let flags: VolAddress<u16, Safe, W1C> =
+    unsafe { VolAddress::new(&mut hw as *mut u16 as usize) };
+  assert_eq!(flags.read(), 0b1010);
+  flags.clear(0b0010);
+  // Plain memory doesn't act like a W1C register, so we just see the mask.
+  assert_eq!(hw, 0b0010);
+}
+
+#[test]
+fn test_voladdress_is_at_reset() {
+  #[derive(Clone, Copy, PartialEq)]
+  struct Ctrl(u8);
+  impl ResetValue for Ctrl {
+    const RESET_VALUE: Self = Ctrl(0x80);
+  }
+  let mut hw = Ctrl(0);
+  let a: VolAddress<Ctrl, Safe, Safe> =
+    unsafe { VolAddress::new(&mut hw as *mut Ctrl as usize) };
+  assert!(!a.is_at_reset());
+  a.reset();
+  assert!(a.is_at_reset());
+}
+
+#[test]
+fn test_voladdress_dummy_reads() {
+  let mut hw = 3_u8;
+  let a: VolAddress<u8, DummyReads<2>, ()> =
+    unsafe { VolAddress::new(&mut hw as *mut u8 as usize) };
+  assert_eq!(a.read(), 3);
+}
+
+#[test]
+fn test_voladdress_read_and_clear() {
+  let mut hw = 0b0110_u8;
+  let status: VolAddress<u8, RC, ()> =
+    unsafe { VolAddress::new(&mut hw as *mut u8 as usize) };
+  assert_eq!(status.read_and_clear(), 0b0110);
+}
+
+#[test]
+fn test_voladdress_write_once() {
+  struct LockKey;
+  let mut hw = 0_u32;
+  let lock: VolAddress<u32, Safe, WriteOnce<LockKey>> =
+    unsafe { VolAddress::new(&mut hw as *mut u32 as usize) };
+  let token = unsafe { WriteOnceToken::<LockKey>::new() };
+  lock.write_once(token, 0xA5);
+  assert_eq!(lock.read(), 0xA5);
+}
+
+#[test]
+fn test_voladdress_unsafe_apply() {
+  let mut hw = 1_u8;
+  let a: VolAddress<u8, Safe, Unsafe> =
+    unsafe { VolAddress::new(&mut hw as *mut u8 as usize) };
+  unsafe { a.apply(|t| *t += 1) };
+  assert_eq!(unsafe { a.exchange(5) }, 2);
+  assert_eq!(a.read(), 5);
+}
+
+impl<T, A> VolAddress<T, Safe, Safe, A>
 where
   T: Copy,
 {
   /// Reads the address, applies the operation, and writes back the new value.
+  #[inline]
+  pub fn apply<F: FnOnce(&mut T)>(self, op: F) {
+    // Safety: both permissions are `Safe`.
+    unsafe { self.apply_raw(op) }
+  }
+
+  /// Reads the old value, writes the new value, and then returns the old value.
   ///
-  /// ## Safety
-  /// * The safety rules of reading/writing this address depend on the device.
-  ///   Consult your hardware manual.
+  /// This is **not** atomic, it's just a normal read followed by a normal
+  /// write. If you need an atomic exchange of a value in RAM, see
+  /// `exchange_atomic` (requires the `atomics` feature).
   #[inline]
-  pub unsafe fn apply<F: FnOnce(&mut T)>(self, op: F) {
-    let mut temp = self.read();
-    op(&mut temp);
-    self.write(temp);
+  pub fn exchange(self, new: T) -> T {
+    // Safety: both permissions are `Safe`.
+    unsafe { self.exchange_raw(new) }
   }
 }
-impl<T> VolAddress<T, Unsafe, Unsafe>
+impl<T, R, W, A> VolAddress<T, R, W, A>
 where
   T: Copy,
+  (R, W): UnsafeReadWrite,
 {
   /// Reads the address, applies the operation, and writes back the new value.
   ///
@@ -322,26 +1255,51 @@ where
   ///   Consult your hardware manual.
   #[inline]
   pub unsafe fn apply<F: FnOnce(&mut T)>(self, op: F) {
-    let mut temp = self.read();
-    op(&mut temp);
-    self.write(temp);
+    self.apply_raw(op)
+  }
+
+  /// Reads the old value, writes the new value, and then returns the old value.
+  ///
+  /// This is **not** atomic, it's just a normal read followed by a normal
+  /// write. If you need an atomic exchange of a value in RAM, see
+  /// `exchange_atomic` (requires the `atomics` feature).
+  ///
+  /// ## Safety
+  /// * The safety rules of reading/writing this address depend on the device.
+  ///   Consult your hardware manual.
+  #[inline]
+  pub unsafe fn exchange(self, new: T) -> T {
+    self.exchange_raw(new)
   }
 }
 
-impl<T, R, W> core::fmt::Debug for VolAddress<T, R, W> {
+impl<T, R, W, A> core::fmt::Debug for VolAddress<T, R, W, A> {
   fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-    write!(
-      f,
-      "VolAddress<{elem_ty}, r{readability}, w{writeability}>(0x{address:#X})",
-      elem_ty = core::any::type_name::<T>(),
-      readability = core::any::type_name::<R>(),
-      writeability = core::any::type_name::<W>(),
-      address = self.address.get()
-    )
+    let space = core::any::type_name::<A>();
+    if space == core::any::type_name::<()>() {
+      write!(
+        f,
+        "VolAddress<{elem_ty}, r{readability}, w{writeability}>(0x{address:#X})",
+        elem_ty = core::any::type_name::<T>(),
+        readability = core::any::type_name::<R>(),
+        writeability = core::any::type_name::<W>(),
+        address = self.address.get()
+      )
+    } else {
+      write!(
+        f,
+        "VolAddress<{elem_ty}, r{readability}, w{writeability}, a{space}>(0x{address:#X})",
+        elem_ty = core::any::type_name::<T>(),
+        readability = core::any::type_name::<R>(),
+        writeability = core::any::type_name::<W>(),
+        space = space,
+        address = self.address.get()
+      )
+    }
   }
 }
 
-impl<T, R, W> core::fmt::Pointer for VolAddress<T, R, W> {
+impl<T, R, W, A> core::fmt::Pointer for VolAddress<T, R, W, A> {
   fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
     write!(f, "0x{address:#X}", address = self.address.get())
   }
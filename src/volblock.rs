@@ -42,6 +42,39 @@ impl<T, R, W, const C: usize> VolBlock<T, R, W, C> {
     Self { base: VolAddress::new(base) }
   }
 
+  /// Constructs the value, checking that the base address is non-zero and
+  /// aligned.
+  ///
+  /// When used to initialize a `const` this check happens at compile time, so
+  /// a typo'd address will fail the build.
+  ///
+  /// ## Panics
+  /// * If the base address is zero or isn't aligned for `T`.
+  ///
+  /// ## Safety
+  /// * As per the type docs, other than the base address being non-zero and
+  ///   aligned.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub const unsafe fn new_checked(base: usize) -> Self {
+    Self { base: VolAddress::new_checked(base) }
+  }
+
+  /// Constructs the value, or gives `None` if the base address is zero.
+  ///
+  /// ## Safety
+  /// * As per the type docs, other than the base address being non-zero.
+  #[inline]
+  #[must_use]
+  pub const unsafe fn try_new(base: usize) -> Option<Self> {
+    if base != 0 {
+      Some(Self::new(base))
+    } else {
+      None
+    }
+  }
+
   /// The length of this block (in elements).
   #[inline]
   #[must_use]
@@ -93,6 +126,24 @@ impl<T, R, W, const C: usize> VolBlock<T, R, W, C> {
     core::ptr::slice_from_raw_parts_mut(self.base.address.get() as *mut T, C)
   }
 
+  /// Views the block as a normal array reference.
+  ///
+  /// This is for memory that acts like normal memory, such as memory mapped
+  /// cartridge ROM, so that data tables can be used with the normal slice
+  /// methods. Accesses through the reference are *not* volatile.
+  ///
+  /// ## Safety
+  /// * The memory must act like normal memory: reads must not have side
+  ///   effects, and the same location must always read the same value.
+  /// * Nothing can write to the memory for the rest of the program, including
+  ///   through this block or any other `VolAddress`.
+  /// * The memory must hold valid values of `T`.
+  #[inline]
+  #[must_use]
+  pub unsafe fn assume_plain_memory(self) -> &'static [T; C] {
+    &*(self.base.address.get() as *const [T; C])
+  }
+
   /// Indexes to the `i`th position of the memory block.
   ///
   /// ## Panics
@@ -105,65 +156,1042 @@ impl<T, R, W, const C: usize> VolBlock<T, R, W, C> {
     unsafe { self.base.add(i) }
   }
 
-  /// Gets the address of the `i`th position, if it's in bounds.
-  #[inline]
-  #[must_use]
-  pub const fn get(self, i: usize) -> Option<VolAddress<T, R, W>> {
-    if i < C {
-      Some(unsafe { self.base.add(i) })
-    } else {
-      None
-    }
-  }
+  /// Indexes to the `i`th position without a bounds check.
+  ///
+  /// This is for hot loops that have already checked their indexes, so that
+  /// they don't pay for a second check or carry a panic path.
+  ///
+  /// ## Safety
+  /// * The index must be in bounds.
+  #[inline]
+  #[must_use]
+  pub const unsafe fn index_unchecked(self, i: usize) -> VolAddress<T, R, W> {
+    self.base.add(i)
+  }
+
+  /// Gets the address of the `i`th position without a bounds check.
+  ///
+  /// This is the same as [`index_unchecked`](Self::index_unchecked), named to
+  /// match the slice method.
+  ///
+  /// ## Safety
+  /// * The index must be in bounds.
+  #[inline]
+  #[must_use]
+  pub const unsafe fn get_unchecked(self, i: usize) -> VolAddress<T, R, W> {
+    self.index_unchecked(i)
+  }
+
+  /// Indexes to the `I`th position of the memory block.
+  ///
+  /// The index is checked against the length when the program is compiled,
+  /// so there's no runtime check and no panic path.
+  ///
+  /// ```
+  /// # use voladdress::*;
+  /// const PALETTE: VolBlock<u16, Safe, Safe, 256> =
+  ///   unsafe { VolBlock::new(0x0500_0000) };
+  /// let backdrop = PALETTE.index_const::<0>();
+  /// assert_eq!(backdrop.as_usize(), 0x0500_0000);
+  /// ```
+  ///
+  /// An out of bounds index doesn't compile:
+  ///
+  /// ```compile_fail
+  /// # use voladdress::*;
+  /// const PALETTE: VolBlock<u16, Safe, Safe, 256> =
+  ///   unsafe { VolBlock::new(0x0500_0000) };
+  /// let _ = PALETTE.index_const::<256>();
+  /// ```
+  #[inline]
+  #[must_use]
+  pub const fn index_const<const I: usize>(self) -> VolAddress<T, R, W> {
+    let () = IndexCheck::<I, C>::IN_BOUNDS;
+    unsafe { self.base.add(I) }
+  }
+
+  /// Gets the address of the `i`th position, if it's in bounds.
+  #[inline]
+  #[must_use]
+  pub const fn get(self, i: usize) -> Option<VolAddress<T, R, W>> {
+    if i < C {
+      Some(unsafe { self.base.add(i) })
+    } else {
+      None
+    }
+  }
+
+  /// If the address is within the memory of the block.
+  ///
+  /// Any byte of any element counts, not just the start of each element.
+  #[inline]
+  #[must_use]
+  pub const fn contains(self, address: usize) -> bool {
+    let base = self.base.as_usize();
+    address >= base && address - base < C * core::mem::size_of::<T>()
+  }
+
+  /// Gets the index of the element that the address is within, if any.
+  ///
+  /// This lets a fault handler (or other debug code) map a raw address back to
+  /// a slot of the block. An address partway into an element gives the index
+  /// of that element.
+  #[inline]
+  #[must_use]
+  pub const fn index_of(self, address: usize) -> Option<usize> {
+    if self.contains(address) {
+      Some((address - self.base.as_usize()) / core::mem::size_of::<T>())
+    } else {
+      None
+    }
+  }
+
+  /// Creates an iterator over the addresses of the memory block.
+  #[inline]
+  #[must_use]
+  pub const fn iter(self) -> VolBlockIter<T, R, W> {
+    VolBlockIter { base: self.base, count: C }
+  }
+
+  /// Creates an iterator over every `step`th element, starting with the
+  /// first.
+  ///
+  /// This is like `iter().step_by(step)`, but each step is a single add,
+  /// which the optimizer has an easier time with.
+  ///
+  /// ## Panics
+  /// * If `step` is 0.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub const fn iter_step(self, step: usize) -> VolStepIter<T, R, W> {
+    assert!(step != 0);
+    let count = if C == 0 { 0 } else { (C - 1) / step + 1 };
+    VolStepIter {
+      base: self.base,
+      count,
+      stride: step * core::mem::size_of::<T>(),
+    }
+  }
+
+  /// Creates an iterator over the index and address of each element.
+  #[inline]
+  #[must_use]
+  pub const fn iter_enumerated(self) -> VolEnumerate<VolBlockIter<T, R, W>> {
+    VolEnumerate { iter: self.iter(), index: 0 }
+  }
+
+  /// Makes an iterator over the range bounds given.
+  ///
+  /// If the range given is empty then your iterator will be empty. Like with
+  /// slices, a range can start at the length (eg: `C..`) to be empty.
+  ///
+  /// ## Panics
+  /// * If the start or end of the range are out of bounds for the block.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub fn iter_range<RB: core::ops::RangeBounds<usize>>(
+    self, r: RB,
+  ) -> VolBlockIter<T, R, W> {
+    // TODO: some day make this a const fn, once start_bound and end_bound are
+    // made into const fn, but that requires const trait impls.
+    use core::ops::Bound;
+    let start_inclusive: usize = match r.start_bound() {
+      Bound::Included(i) => *i,
+      Bound::Excluded(x) => x + 1,
+      Bound::Unbounded => 0,
+    };
+    assert!(start_inclusive <= C);
+    let end_exclusive: usize = match r.end_bound() {
+      Bound::Included(i) => i + 1,
+      Bound::Excluded(x) => *x,
+      Bound::Unbounded => C,
+    };
+    assert!(end_exclusive <= C);
+    let count = end_exclusive.saturating_sub(start_inclusive);
+    VolBlockIter { base: unsafe { self.base.add(start_inclusive) }, count }
+  }
+
+  /// Gets the range given as a region, if it's in bounds.
+  ///
+  /// Unlike [`iter_range`](Self::iter_range) this doesn't panic, so it's
+  /// suitable for ranges that come from runtime data.
+  #[inline]
+  #[must_use]
+  pub fn get_range<RB: core::ops::RangeBounds<usize>>(
+    self, r: RB,
+  ) -> Option<VolRegion<T, R, W>> {
+    use core::ops::Bound;
+    let start_inclusive: usize = match r.start_bound() {
+      Bound::Included(i) => *i,
+      Bound::Excluded(x) => x.checked_add(1)?,
+      Bound::Unbounded => 0,
+    };
+    let end_exclusive: usize = match r.end_bound() {
+      Bound::Included(i) => i.checked_add(1)?,
+      Bound::Excluded(x) => *x,
+      Bound::Unbounded => C,
+    };
+    if start_inclusive <= end_exclusive && end_exclusive <= C {
+      Some(VolRegion {
+        addr: unsafe { self.base.add(start_inclusive) },
+        len: end_exclusive - start_inclusive,
+      })
+    } else {
+      None
+    }
+  }
+
+  /// View the volatile block as an equivalent spanned region.
+  ///
+  /// This method exists because unfortunately the typing of the `Deref` trait
+  /// doesn't allow for a Block to deref into a Region, so we have to provide
+  /// the conversion through this manual method.
+  #[inline]
+  #[must_use]
+  pub const fn as_region(self) -> VolRegion<T, R, W> {
+    VolRegion { addr: self.base, len: C }
+  }
+
+  /// Carves a block out of a region, starting `byte_offset` bytes from the
+  /// start of the region.
+  ///
+  /// The region can have any element type, so this works for finding a fixed
+  /// layout structure within a span of bytes (eg: a shared memory area found
+  /// at runtime).
+  ///
+  /// Gives `None` if the block wouldn't fit entirely within the region, or if
+  /// the block's address wouldn't be aligned for `T`.
+  ///
+  /// ## Safety
+  /// * The memory at the block's location must be legal to access as `T`
+  ///   values with the `R` and `W` permissions. The region only vouches for
+  ///   accessing `U` values, and some bit patterns of a `U` might not be valid
+  ///   for a `T` (eg: a `u8` region viewed as a block of `bool`).
+  #[inline]
+  #[must_use]
+  pub unsafe fn try_from_region_at<U>(
+    region: VolRegion<U, R, W>, byte_offset: usize,
+  ) -> Option<Self> {
+    let region_bytes = region.len() * core::mem::size_of::<U>();
+    let block_bytes = C * core::mem::size_of::<T>();
+    if byte_offset > region_bytes || block_bytes > region_bytes - byte_offset {
+      return None;
+    }
+    let address = region.as_usize() + byte_offset;
+    if address & (core::mem::align_of::<T>() - 1) != 0 {
+      return None;
+    }
+    // Note: the block is entirely within the region, and aligned.
+    Some(VolBlock { base: VolAddress::new(address) })
+  }
+
+  /// Splits the block into a read-only view and a write-only view of the same
+  /// memory.
+  ///
+  /// The two halves can then be handed out separately, so that each user only
+  /// gets the capability that it needs.
+  #[inline]
+  #[must_use]
+  pub const fn split_rw(
+    self,
+  ) -> (VolBlock<T, R, (), C>, VolBlock<T, (), W, C>) {
+    (self.as_readonly(), self.as_writeonly())
+  }
+
+  /// Removes the write permission, giving a read-only block.
+  #[inline]
+  #[must_use]
+  pub const fn as_readonly(self) -> VolBlock<T, R, (), C> {
+    VolBlock { base: self.base.as_readonly() }
+  }
+
+  /// Removes the read permission, giving a write-only block.
+  #[inline]
+  #[must_use]
+  pub const fn as_writeonly(self) -> VolBlock<T, (), W, C> {
+    VolBlock { base: self.base.as_writeonly() }
+  }
+
+  /// Narrows the block to the `LEN` elements starting at index `START`.
+  ///
+  /// This lets you give just part of a block (eg: one screenblock out of all
+  /// of VRAM) to other code, while keeping the length in the type.
+  ///
+  /// ## Panics
+  /// * If `START + LEN` is more than `C`. When used to initialize a `const`
+  ///   this check happens at compile time.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub const fn sub_block<const START: usize, const LEN: usize>(
+    self,
+  ) -> VolBlock<T, R, W, LEN> {
+    assert!(START <= C && LEN <= C - START);
+    // SAFETY: the new block is entirely within this block.
+    VolBlock { base: unsafe { self.base.add(START) } }
+  }
+
+  /// Makes an iterator over the block in chunks of `N` elements, each of which
+  /// is its own block.
+  ///
+  /// This lets code work on memory in tile sized (or cache line sized) groups
+  /// with the group size in the type.
+  ///
+  /// ## Panics
+  /// * If `N` is zero, or if `C` isn't a multiple of `N`. There's no partial
+  ///   chunk at the end.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub const fn chunks<const N: usize>(self) -> VolBlockChunks<T, R, W, N> {
+    assert!(N != 0 && C / N * N == C);
+    VolBlockChunks {
+      // SAFETY: the block is exactly `C / N` arrays of `N` elements.
+      iter: VolBlockIter { base: unsafe { self.base.cast() }, count: C / N },
+    }
+  }
+
+  /// Views every `N`th element, starting at index `OFFSET`, as a series.
+  ///
+  /// This lets interleaved layouts (eg: attribute words mixed in with other
+  /// data) be used from a single block declaration.
+  ///
+  /// The series has `M` elements spaced `S` bytes apart. Rust can't yet
+  /// compute these from the other parameters, so you give them and they're
+  /// checked.
+  ///
+  /// ## Panics
+  /// * If `N` is zero or `OFFSET` is not less than `N`.
+  /// * If `S` isn't `N * size_of::<T>()`.
+  /// * If `M` isn't the number of elements at `OFFSET`, `OFFSET + N`,
+  ///   `OFFSET + 2 * N`, and so on, that are in the block. When `C` is a
+  ///   multiple of `N` this is `C / N`.
+  ///
+  /// When used to initialize a `const` these checks happen at compile time.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub const fn every_nth<
+    const N: usize,
+    const OFFSET: usize,
+    const M: usize,
+    const S: usize,
+  >(
+    self,
+  ) -> VolSeries<T, R, W, M, S> {
+    assert!(N != 0 && OFFSET < N);
+    assert!(S == N * core::mem::size_of::<T>());
+    // The last element of the series is in the block, and the next isn't.
+    assert!(M == 0 || OFFSET + (M - 1) * N < C);
+    assert!(OFFSET + M * N >= C);
+    // SAFETY: every element of the series is an element of this block.
+    VolSeries { base: unsafe { self.base.add(OFFSET) } }
+  }
+
+  /// Views the block as a block of `M` arrays, each of `N` elements.
+  ///
+  /// For example, 4096 `u32` of charblock memory can be viewed as 512 tiles
+  /// of `[u32; 8]`, and then each tile can be copied with a single write.
+  ///
+  /// ## Panics
+  /// * If `N * M` isn't exactly `C`. When used to initialize a `const` this
+  ///   check happens at compile time.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub const fn as_array_block<const N: usize, const M: usize>(
+    self,
+  ) -> VolBlock<[T; N], R, W, M> {
+    assert!(N * M == C);
+    // SAFETY: the new block covers exactly the same memory.
+    VolBlock { base: unsafe { self.base.cast() } }
+  }
+
+  /// Changes the target type from `T` to `Z`, for plain data types.
+  ///
+  /// Because both types are [`Pod`](bytemuck::Pod), any value of one type is
+  /// a valid value of the other, so unlike `cast` this is safe. This is useful
+  /// for things like viewing a framebuffer of `u16` as `[u8; 2]` instead.
+  ///
+  /// ## Panics
+  /// * If `Z` isn't the same size as `T`.
+  /// * If `Z` has a larger alignment than `T`.
+  #[cfg(feature = "bytemuck")]
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub fn cast_pod<Z>(self) -> VolBlock<Z, R, W, C>
+  where
+    T: bytemuck::Pod,
+    Z: bytemuck::Pod,
+  {
+    VolBlock { base: self.base.cast_pod() }
+  }
+
+  /// Views the block as a block of its `B` bytes.
+  ///
+  /// This is for byte level work on any block of plain data, such as
+  /// checksums, memory dumps, or setting up a DMA transfer.
+  ///
+  /// ## Panics
+  /// * If `B` isn't exactly `C * size_of::<T>()`.
+  #[cfg(feature = "bytemuck")]
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub fn as_byte_block<const B: usize>(self) -> VolBlock<u8, R, W, B>
+  where
+    T: bytemuck::Pod,
+  {
+    assert!(B == C * core::mem::size_of::<T>());
+    // Safety: the new block covers exactly the same memory, and any bit
+    // pattern is valid for both types.
+    VolBlock { base: unsafe { self.base.cast() } }
+  }
+}
+
+impl<T, W, const C: usize> VolBlock<T, Safe, W, C> {
+  /// Makes an iterator that volatile reads each element, from the last
+  /// element to the first.
+  ///
+  /// Each element is only read when the iterator reaches it.
+  #[inline]
+  #[must_use]
+  pub const fn iter_rev_values(self) -> VolRevValues<T, W> {
+    VolRevValues { iter: self.iter() }
+  }
+}
+
+impl<T, W, const C: usize> VolBlock<T, Safe, W, C>
+where
+  T: Copy,
+{
+  /// Volatile reads each element into the provided buffer, going from the last
+  /// element of the block to the first.
+  ///
+  /// The buffer is filled in the order that the reads happen, so `buffer[0]`
+  /// gets the block's last element.
+  ///
+  /// ## Panics
+  /// * If the buffer's length is not *exactly* this block's length.
+  #[inline]
+  #[track_caller]
+  pub fn read_to_slice_rev(self, buffer: &mut [T]) {
+    assert_eq!(C, buffer.len());
+    self.iter().rev().zip(buffer.iter_mut()).for_each(|(va, s)| *s = va.read())
+  }
+
+  /// Volatile reads every element, in order, into a new array.
+  ///
+  /// The array's length is the block's length, so there's no bounds checks.
+  #[inline]
+  #[must_use]
+  pub fn read_all(self) -> [T; C] {
+    let mut out: MaybeUninit<[T; C]> = MaybeUninit::uninit();
+    let p = out.as_mut_ptr() as *mut T;
+    for i in 0..C {
+      // Safety: `i` is in bounds for both the block and the array.
+      unsafe { p.add(i).write(self.base.add(i).read()) };
+    }
+    // Safety: every element of the array was just initialized.
+    unsafe { out.assume_init() }
+  }
+
+  /// Volatile reads every element, in order, into the array given.
+  ///
+  /// The array's length is the block's length, so there's no bounds checks.
+  #[inline]
+  pub fn read_all_into(self, buffer: &mut [T; C]) {
+    self.iter().zip(buffer.iter_mut()).for_each(|(va, s)| *s = va.read())
+  }
+
+  /// Binary searches a sorted block with a comparison function.
+  ///
+  /// This works like
+  /// [`slice::binary_search_by`](https://doc.rust-lang.org/core/primitive.slice.html#method.binary_search_by),
+  /// except that the function gets each element by value, from a volatile
+  /// read. This lets tables in memory mapped ROM be searched without copying
+  /// them into RAM first.
+  ///
+  /// Gives `Ok` with the index of a matching element, or `Err` with the index
+  /// where a matching element could be inserted to keep the order.
+  #[inline]
+  pub fn binary_search_by<F>(self, mut f: F) -> Result<usize, usize>
+  where
+    F: FnMut(T) -> core::cmp::Ordering,
+  {
+    let mut low = 0;
+    let mut high = C;
+    while low < high {
+      let mid = low + (high - low) / 2;
+      match f(self.index(mid).read()) {
+        core::cmp::Ordering::Less => low = mid + 1,
+        core::cmp::Ordering::Greater => high = mid,
+        core::cmp::Ordering::Equal => return Ok(mid),
+      }
+    }
+    Err(low)
+  }
+}
+impl<T, W, const C: usize> VolBlock<T, Unsafe, W, C>
+where
+  T: Copy,
+{
+  /// Volatile reads each element into the provided buffer, going from the last
+  /// element of the block to the first.
+  ///
+  /// The buffer is filled in the order that the reads happen, so `buffer[0]`
+  /// gets the block's last element.
+  ///
+  /// ## Panics
+  /// * If the buffer's length is not *exactly* this block's length.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  #[track_caller]
+  pub unsafe fn read_to_slice_rev(self, buffer: &mut [T]) {
+    assert_eq!(C, buffer.len());
+    self.iter().rev().zip(buffer.iter_mut()).for_each(|(va, s)| *s = va.read())
+  }
+
+  /// Volatile reads every element, in order, into a new array.
+  ///
+  /// The array's length is the block's length, so there's no bounds checks.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  #[must_use]
+  pub unsafe fn read_all(self) -> [T; C] {
+    let mut out: MaybeUninit<[T; C]> = MaybeUninit::uninit();
+    let p = out.as_mut_ptr() as *mut T;
+    for i in 0..C {
+      p.add(i).write(self.base.add(i).read());
+    }
+    out.assume_init()
+  }
+
+  /// Volatile reads every element, in order, into the array given.
+  ///
+  /// The array's length is the block's length, so there's no bounds checks.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn read_all_into(self, buffer: &mut [T; C]) {
+    self.iter().zip(buffer.iter_mut()).for_each(|(va, s)| *s = va.read())
+  }
+
+  /// Binary searches a sorted block with a comparison function.
+  ///
+  /// This works like
+  /// [`slice::binary_search_by`](https://doc.rust-lang.org/core/primitive.slice.html#method.binary_search_by),
+  /// except that the function gets each element by value, from a volatile
+  /// read. This lets tables in memory mapped ROM be searched without copying
+  /// them into RAM first.
+  ///
+  /// Gives `Ok` with the index of a matching element, or `Err` with the index
+  /// where a matching element could be inserted to keep the order.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn binary_search_by<F>(self, mut f: F) -> Result<usize, usize>
+  where
+    F: FnMut(T) -> core::cmp::Ordering,
+  {
+    let mut low = 0;
+    let mut high = C;
+    while low < high {
+      let mid = low + (high - low) / 2;
+      match f(self.index(mid).read()) {
+        core::cmp::Ordering::Less => low = mid + 1,
+        core::cmp::Ordering::Greater => high = mid,
+        core::cmp::Ordering::Equal => return Ok(mid),
+      }
+    }
+    Err(low)
+  }
+}
+
+impl<T, R, const C: usize> VolBlock<T, R, Safe, C>
+where
+  T: Copy,
+{
+  /// Volatile writes `f(i)` to each element `i`, in order.
+  ///
+  /// This lets you generate data (such as a gradient, a lookup table, or a test
+  /// pattern) directly into the memory, without a staging buffer.
+  #[inline]
+  pub fn write_with<F: FnMut(usize) -> T>(self, mut f: F) {
+    self.iter().enumerate().for_each(|(i, va)| va.write(f(i)))
+  }
+
+  /// Volatile writes every element of the array, in order.
+  ///
+  /// The array's length is the block's length, so there's no bounds checks.
+  #[inline]
+  pub fn write_all(self, buffer: &[T; C]) {
+    self.iter().zip(buffer.iter()).for_each(|(va, s)| va.write(*s))
+  }
+
+  /// Volatile writes each item of the iterator to the next element, in order.
+  ///
+  /// Unlike using `zip`, a wrong length is not silently ignored.
+  ///
+  /// ## Panics
+  /// * If the iterator gives fewer than `C` items. The items that it did give
+  ///   are written before the panic.
+  /// * If the iterator gives more than `C` items. All `C` elements are written
+  ///   before the panic.
+  #[inline]
+  #[track_caller]
+  pub fn write_from_iter<I: IntoIterator<Item = T>>(self, iter: I) {
+    let mut iter = iter.into_iter();
+    self.iter().for_each(|va| {
+      va.write(iter.next().expect("iterator gave too few items"))
+    });
+    assert!(iter.next().is_none(), "iterator gave too many items");
+  }
+
+  /// Volatile writes the value to every element, in order.
+  #[inline]
+  pub fn fill(self, value: T) {
+    self.iter().for_each(|va| va.write(value))
+  }
+
+  /// Volatile writes `f(i)` to each element `i`, in order.
+  ///
+  /// This is the same as [`write_with`](Self::write_with), named to go with
+  /// [`fill`](Self::fill).
+  #[inline]
+  pub fn fill_with<F: FnMut(usize) -> T>(self, f: F) {
+    self.write_with(f)
+  }
+
+  /// Volatile writes the default value of `T` to every element.
+  #[inline]
+  pub fn write_default(self)
+  where
+    T: Default,
+  {
+    self.iter().for_each(|va| va.write(T::default()))
+  }
+
+  /// Volatile writes the all-zeroes value of `T` to every element.
+  #[cfg(feature = "bytemuck")]
+  #[inline]
+  pub fn write_zeroed(self)
+  where
+    T: bytemuck::Zeroable,
+  {
+    self.iter().for_each(|va| va.write(T::zeroed()))
+  }
+
+  /// Volatile writes the reset value of `T` to every element.
+  #[inline]
+  pub fn reset(self)
+  where
+    T: ResetValue,
+  {
+    self.iter().for_each(|va| va.write(T::RESET_VALUE))
+  }
+}
+impl<T, R, const C: usize> VolBlock<T, R, Unsafe, C>
+where
+  T: Copy,
+{
+  /// Volatile writes `f(i)` to each element `i`, in order.
+  ///
+  /// This lets you generate data (such as a gradient, a lookup table, or a test
+  /// pattern) directly into the memory, without a staging buffer.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn write_with<F: FnMut(usize) -> T>(self, mut f: F) {
+    self.iter().enumerate().for_each(|(i, va)| va.write(f(i)))
+  }
+
+  /// Volatile writes every element of the array, in order.
+  ///
+  /// The array's length is the block's length, so there's no bounds checks.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn write_all(self, buffer: &[T; C]) {
+    self.iter().zip(buffer.iter()).for_each(|(va, s)| va.write(*s))
+  }
+
+  /// Volatile writes each item of the iterator to the next element, in order.
+  ///
+  /// Unlike using `zip`, a wrong length is not silently ignored.
+  ///
+  /// ## Panics
+  /// * If the iterator gives fewer than `C` items. The items that it did give
+  ///   are written before the panic.
+  /// * If the iterator gives more than `C` items. All `C` elements are written
+  ///   before the panic.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  #[track_caller]
+  pub unsafe fn write_from_iter<I: IntoIterator<Item = T>>(self, iter: I) {
+    let mut iter = iter.into_iter();
+    self.iter().for_each(|va| {
+      va.write(iter.next().expect("iterator gave too few items"))
+    });
+    assert!(iter.next().is_none(), "iterator gave too many items");
+  }
+
+  /// Volatile writes the value to every element, in order.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn fill(self, value: T) {
+    self.iter().for_each(|va| va.write(value))
+  }
+
+  /// Volatile writes `f(i)` to each element `i`, in order.
+  ///
+  /// This is the same as [`write_with`](Self::write_with), named to go with
+  /// [`fill`](Self::fill).
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn fill_with<F: FnMut(usize) -> T>(self, f: F) {
+    self.write_with(f)
+  }
+
+  /// Volatile writes the default value of `T` to every element.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn write_default(self)
+  where
+    T: Default,
+  {
+    self.iter().for_each(|va| va.write(T::default()))
+  }
+
+  /// Volatile writes the all-zeroes value of `T` to every element.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[cfg(feature = "bytemuck")]
+  #[inline]
+  pub unsafe fn write_zeroed(self)
+  where
+    T: bytemuck::Zeroable,
+  {
+    self.iter().for_each(|va| va.write(T::zeroed()))
+  }
+
+  /// Volatile writes the reset value of `T` to every element.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn reset(self)
+  where
+    T: ResetValue,
+  {
+    self.iter().for_each(|va| va.write(T::RESET_VALUE))
+  }
+}
+
+impl<T, const C: usize> VolBlock<T, Safe, Safe, C>
+where
+  T: Copy,
+{
+  /// Reads each element, passes it through the function, and writes back the
+  /// new value.
+  ///
+  /// Elements are updated in order, one at a time.
+  #[inline]
+  pub fn update_each<F: FnMut(T) -> T>(self, mut f: F) {
+    self.iter().for_each(|va| va.write(f(va.read())))
+  }
+}
+impl<T, R, W, const C: usize> VolBlock<T, R, W, C>
+where
+  T: Copy,
+  (R, W): UnsafeReadWrite,
+{
+  /// Reads each element, passes it through the function, and writes back the
+  /// new value.
+  ///
+  /// Elements are updated in order, one at a time.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading/writing this address depend on the device.
+  ///   Consult your hardware manual.
+  #[inline]
+  pub unsafe fn update_each<F: FnMut(T) -> T>(self, mut f: F) {
+    self.iter().for_each(|va| va.write_raw(f(va.read_raw())))
+  }
+}
+
+#[test]
+fn test_volblock_try_from_region_at() {
+  let mut hw = [0_u32; 4];
+  let region: VolRegion<u8, Safe, Safe> = unsafe {
+    VolRegion::from_raw_parts(VolAddress::new(hw.as_mut_ptr() as usize), 16)
+  };
+  let try_at = |offset| unsafe {
+    VolBlock::<u32, Safe, Safe, 2>::try_from_region_at(region, offset)
+  };
+  try_at(8).unwrap().index(1).write(5);
+  assert_eq!(hw[3], 5);
+  assert!(try_at(9).is_none());
+  assert!(try_at(12).is_none());
+  assert!(unsafe {
+    VolBlock::<u32, Safe, Safe, 1>::try_from_region_at(region, 20).is_none()
+  });
+}
+
+#[test]
+fn test_volblock_sub_block() {
+  const VRAM: VolBlock<u16, Safe, Safe, 0x400> =
+    unsafe { VolBlock::new(0x1000) };
+  const SCREENBLOCK: VolBlock<u16, Safe, Safe, 0x100> =
+    VRAM.sub_block::<0x200, 0x100>();
+  assert_eq!(SCREENBLOCK.as_usize(), 0x1400);
+  assert_eq!(VRAM.sub_block::<0x400, 0>().len(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_volblock_sub_block_panic() {
+  let block: VolBlock<u16, Safe, Safe, 4> = unsafe { VolBlock::new(0x1000) };
+  let _ = block.sub_block::<3, 2>();
+}
+
+#[test]
+fn test_volblock_chunks() {
+  let block: VolBlock<u32, Safe, Safe, 16> = unsafe { VolBlock::new(0x1000) };
+  let mut chunks = block.chunks::<8>();
+  assert_eq!(chunks.len(), 2);
+  assert_eq!(chunks.next().unwrap().as_usize(), 0x1000);
+  assert_eq!(chunks.next().unwrap().as_usize(), 0x1020);
+  assert!(chunks.next().is_none());
+  assert_eq!(block.chunks::<4>().next_back().unwrap().as_usize(), 0x1030);
+}
+
+#[test]
+#[should_panic]
+fn test_volblock_chunks_panic() {
+  let block: VolBlock<u32, Safe, Safe, 16> = unsafe { VolBlock::new(0x1000) };
+  let _ = block.chunks::<5>();
+}
+
+#[test]
+fn test_volblock_as_array_block() {
+  const CHARBLOCK: VolBlock<u32, Safe, Safe, 4096> =
+    unsafe { VolBlock::new(0x0600_0000) };
+  const TILES: VolBlock<[u32; 8], Safe, Safe, 512> =
+    CHARBLOCK.as_array_block::<8, 512>();
+  assert_eq!(TILES.index(1).as_usize(), 0x0600_0020);
+  //
+  let mut hw = [0_u16; 4];
+  let block: VolBlock<u16, Safe, Safe, 4> =
+    unsafe { VolBlock::new(hw.as_mut_ptr() as usize) };
+  block.as_array_block::<2, 2>().index(1).write([5, 6]);
+  assert_eq!(hw, [0, 0, 5, 6]);
+}
+
+#[test]
+#[cfg(feature = "bytemuck")]
+fn test_volblock_as_byte_block() {
+  let mut hw = [0x0102_u16, 0x0304];
+  let block: VolBlock<u16, Safe, Safe, 2> =
+    unsafe { VolBlock::new(hw.as_mut_ptr() as usize) };
+  let bytes: VolBlock<u8, Safe, Safe, 4> = block.as_byte_block();
+  let sum: u32 = bytes.iter().map(|va| u32::from(va.read())).sum();
+  assert_eq!(sum, 1 + 2 + 3 + 4);
+}
+
+#[test]
+#[cfg(feature = "bytemuck")]
+#[should_panic]
+fn test_volblock_as_byte_block_panic() {
+  let block: VolBlock<u16, Safe, Safe, 2> = unsafe { VolBlock::new(0x1000) };
+  let _: VolBlock<u8, Safe, Safe, 2> = block.as_byte_block();
+}
+
+#[test]
+fn test_volblock_iter_enumerated() {
+  let block: VolBlock<u16, Safe, Safe, 4> = unsafe { VolBlock::new(0x1000) };
+  let mut iter = block.iter_enumerated();
+  assert_eq!(iter.next(), Some((0, block.index(0))));
+  assert_eq!(iter.nth(1), Some((2, block.index(2))));
+  assert_eq!(iter.next_back(), Some((3, block.index(3))));
+  assert_eq!(iter.next(), None);
+  //
+  let mut iter = block.iter_enumerated();
+  assert_eq!(iter.nth_back(1), Some((2, block.index(2))));
+  assert_eq!(iter.next_back(), Some((1, block.index(1))));
+  assert_eq!(iter.len(), 1);
+}
+
+#[test]
+fn test_volblock_contains_index_of() {
+  let block: VolBlock<u32, Safe, Safe, 4> = unsafe { VolBlock::new(0x1000) };
+  assert!(block.contains(0x1000));
+  assert!(block.contains(0x100F));
+  assert!(!block.contains(0x1010));
+  assert!(!block.contains(0xFFF));
+  assert_eq!(block.index_of(0x1008), Some(2));
+  assert_eq!(block.index_of(0x100B), Some(2));
+  assert_eq!(block.index_of(0x1010), None);
+}
+
+#[test]
+fn test_volblock_write_from_iter() {
+  let mut hw = [0_u8; 4];
+  let block: VolBlock<u8, Safe, Safe, 4> =
+    unsafe { VolBlock::new(hw.as_mut_ptr() as usize) };
+  block.write_from_iter(1..=4);
+  assert_eq!(hw, [1, 2, 3, 4]);
+}
+
+#[test]
+#[should_panic]
+fn test_volblock_write_from_iter_short_panic() {
+  let mut hw = [0_u8; 4];
+  let block: VolBlock<u8, Safe, Safe, 4> =
+    unsafe { VolBlock::new(hw.as_mut_ptr() as usize) };
+  block.write_from_iter(1..4);
+}
+
+#[test]
+#[should_panic]
+fn test_volblock_write_from_iter_long_panic() {
+  let mut hw = [0_u8; 4];
+  let block: VolBlock<u8, Safe, Safe, 4> =
+    unsafe { VolBlock::new(hw.as_mut_ptr() as usize) };
+  block.write_from_iter(1..6);
+}
+
+#[test]
+fn test_volblock_every_nth() {
+  const BLOCK: VolBlock<u16, Safe, Safe, 8> = unsafe { VolBlock::new(0x1000) };
+  const ATTRS: VolSeries<u16, Safe, Safe, 4, 4> =
+    BLOCK.every_nth::<2, 1, 4, 4>();
+  assert_eq!(ATTRS.index(0).as_usize(), 0x1002);
+  assert_eq!(ATTRS.index(3).as_usize(), 0x100E);
+  let thirds: VolSeries<u16, Safe, Safe, 3, 6> =
+    BLOCK.every_nth::<3, 1, 3, 6>();
+  assert_eq!(thirds.index(2).as_usize(), 0x100E);
+}
+
+#[test]
+#[should_panic]
+fn test_volblock_every_nth_panic() {
+  let block: VolBlock<u16, Safe, Safe, 8> = unsafe { VolBlock::new(0x1000) };
+  let _ = block.every_nth::<2, 0, 5, 4>();
+}
+
+#[test]
+fn test_volblock_iter_step() {
+  let block: VolBlock<u16, Safe, Safe, 7> = unsafe { VolBlock::new(0x1000) };
+  let mut iter = block.iter_step(3);
+  assert_eq!(iter.len(), 3);
+  assert_eq!(iter.next(), Some(block.index(0)));
+  assert_eq!(iter.next_back(), Some(block.index(6)));
+  assert_eq!(iter.next(), Some(block.index(3)));
+  assert_eq!(iter.next(), None);
+  assert_eq!(block.iter_step(2).last(), Some(block.index(6)));
+  assert_eq!(block.iter_step(10).count(), 1);
+}
+
+#[test]
+fn test_volblock_binary_search_by() {
+  let mut hw = [1_u16, 3, 5, 7, 9];
+  let block: VolBlock<u16, Safe, (), 5> =
+    unsafe { VolBlock::new(hw.as_mut_ptr() as usize) };
+  assert_eq!(block.binary_search_by(|t| t.cmp(&7)), Ok(3));
+  assert_eq!(block.binary_search_by(|t| t.cmp(&1)), Ok(0));
+  assert_eq!(block.binary_search_by(|t| t.cmp(&4)), Err(2));
+  assert_eq!(block.binary_search_by(|t| t.cmp(&10)), Err(5));
+}
+
+#[test]
+fn test_volblock_assume_plain_memory() {
+  static TABLE: [u8; 4] = [2, 4, 6, 8];
+  let block: VolBlock<u8, Safe, (), 4> =
+    unsafe { VolBlock::new(TABLE.as_ptr() as usize) };
+  let table: &'static [u8; 4] = unsafe { block.assume_plain_memory() };
+  assert_eq!(table.iter().position(|&x| x == 6), Some(2));
+}
+
+#[test]
+fn test_volblock_read_all() {
+  let mut hw = [1_u16, 2, 3, 4];
+  let block: VolBlock<u16, Safe, Safe, 4> =
+    unsafe { VolBlock::new(hw.as_mut_ptr() as usize) };
+  assert_eq!(block.read_all(), [1, 2, 3, 4]);
+  let mut buf = [0_u16; 4];
+  block.read_all_into(&mut buf);
+  assert_eq!(buf, [1, 2, 3, 4]);
+}
 
-  /// Creates an iterator over the addresses of the memory block.
-  #[inline]
-  #[must_use]
-  pub const fn iter(self) -> VolBlockIter<T, R, W> {
-    VolBlockIter { base: self.base, count: C }
-  }
+#[test]
+fn test_volblock_write_all() {
+  let mut hw = [0_u16; 4];
+  let block: VolBlock<u16, (), Safe, 4> =
+    unsafe { VolBlock::new(hw.as_mut_ptr() as usize) };
+  block.write_all(&[5, 6, 7, 8]);
+  assert_eq!(hw, [5, 6, 7, 8]);
+}
 
-  /// Makes an iterator over the range bounds given.
-  ///
-  /// If the range given is empty then your iterator will be empty.
-  ///
-  /// ## Panics
-  /// * If the start or end of the range are out of bounds for the block.
-  #[inline]
-  #[must_use]
-  #[track_caller]
-  pub fn iter_range<RB: core::ops::RangeBounds<usize>>(
-    self, r: RB,
-  ) -> VolBlockIter<T, R, W> {
-    // TODO: some day make this a const fn, once start_bound and end_bound are
-    // made into const fn, but that requires const trait impls.
-    use core::ops::Bound;
-    let start_inclusive: usize = match r.start_bound() {
-      Bound::Included(i) => *i,
-      Bound::Excluded(x) => x + 1,
-      Bound::Unbounded => 0,
-    };
-    assert!(start_inclusive < C);
-    let end_exclusive: usize = match r.end_bound() {
-      Bound::Included(i) => i + 1,
-      Bound::Excluded(x) => *x,
-      Bound::Unbounded => C,
-    };
-    assert!(end_exclusive <= C);
-    let count = end_exclusive.saturating_sub(start_inclusive);
-    VolBlockIter { base: self.index(start_inclusive), count }
-  }
+#[test]
+fn test_volblock_fill() {
+  let mut hw = [1_u16, 2, 3, 4];
+  let block: VolBlock<u16, (), Safe, 4> =
+    unsafe { VolBlock::new(hw.as_mut_ptr() as usize) };
+  block.fill(9);
+  assert_eq!(hw, [9; 4]);
+  block.fill_with(|i| 10 + i as u16);
+  assert_eq!(hw, [10, 11, 12, 13]);
+}
 
-  /// View the volatile block as an equivalent spanned region.
-  ///
-  /// This method exists because unfortunately the typing of the `Deref` trait
-  /// doesn't allow for a Block to deref into a Region, so we have to provide
-  /// the conversion through this manual method.
-  #[inline]
-  #[must_use]
-  pub const fn as_region(self) -> VolRegion<T, R, W> {
-    VolRegion { addr: self.base, len: C }
-  }
+#[test]
+fn test_volblock_write_default() {
+  let mut hw = [1_u8, 2, 3, 4];
+  let block: VolBlock<u8, (), Safe, 4> =
+    unsafe { VolBlock::new(hw.as_mut_ptr() as usize) };
+  block.write_default();
+  assert_eq!(hw, [0; 4]);
+}
+
+#[test]
+fn test_volblock_update_each() {
+  let mut hw = [1_u8, 2, 3, 4];
+  let block: VolBlock<u8, Safe, Safe, 4> =
+    unsafe { VolBlock::new(hw.as_mut_ptr() as usize) };
+  block.update_each(|t| t * 2);
+  assert_eq!(hw, [2, 4, 6, 8]);
 }
 
 #[test]
@@ -189,6 +1217,23 @@ fn test_volblock_iter_range() {
   let i = block.iter_range(..=4);
   assert_eq!(i.base.as_usize(), 1);
   assert_eq!(i.count, 5);
+  //
+  let i = block.iter_range(10..);
+  assert_eq!(i.count, 0);
+}
+
+#[test]
+fn test_volblock_get_range() {
+  let block: VolBlock<u8, Safe, Safe, 10> = unsafe { VolBlock::new(1) };
+  let r = block.get_range(2..=5).unwrap();
+  assert_eq!(r.as_usize(), 1 + 2);
+  assert_eq!(r.len(), 4);
+  assert_eq!(block.get_range(10..).unwrap().len(), 0);
+  assert!(block.get_range(..=10).is_none());
+  assert!(block.get_range(11..).is_none());
+  let (start, end) = (5, 3);
+  assert!(block.get_range(start..end).is_none());
+  assert!(block.get_range(..=usize::MAX).is_none());
 }
 
 #[test]
@@ -196,7 +1241,7 @@ fn test_volblock_iter_range() {
 fn test_volblock_iter_range_low_bound_panic() {
   let block: VolBlock<u8, Unsafe, Unsafe, 10> = unsafe { VolBlock::new(1) };
   //
-  let _i = block.iter_range(10..);
+  let _i = block.iter_range(11..);
 }
 
 #[test]
@@ -224,6 +1269,12 @@ impl<T, R, W, const C: usize> core::fmt::Pointer for VolBlock<T, R, W, C> {
   }
 }
 
+/// Compile time bounds check for [`VolBlock::index_const`].
+struct IndexCheck<const I: usize, const C: usize>;
+impl<const I: usize, const C: usize> IndexCheck<I, C> {
+  const IN_BOUNDS: () = assert!(I < C, "index out of bounds");
+}
+
 /// An iterator over a volatile block.
 ///
 /// You will generally not construct types of this value yourself. Instead, you
@@ -242,6 +1293,29 @@ impl<T, R, W> Clone for VolBlockIter<T, R, W> {
   }
 }
 
+impl<T, R, W> PartialEq for VolBlockIter<T, R, W> {
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    self.base.as_usize() == other.base.as_usize() && self.count == other.count
+  }
+}
+impl<T, R, W> Eq for VolBlockIter<T, R, W> {}
+
+impl<T, R, W> core::fmt::Debug for VolBlockIter<T, R, W> {
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(
+      f,
+      "VolBlockIter<{elem_ty}, r{readability}, w{writeability}> {{ base: {address:#X}, count: {count} }}",
+      elem_ty = core::any::type_name::<T>(),
+      readability = core::any::type_name::<R>(),
+      writeability = core::any::type_name::<W>(),
+      address = self.base.as_usize(),
+      count = self.count
+    )
+  }
+}
+
 impl<T, R, W> core::iter::Iterator for VolBlockIter<T, R, W> {
   type Item = VolAddress<T, R, W>;
 
@@ -250,7 +1324,10 @@ impl<T, R, W> core::iter::Iterator for VolBlockIter<T, R, W> {
     if n < self.count {
       let out = Some(unsafe { self.base.add(n) });
       self.count -= n + 1;
-      self.base = unsafe { self.base.add(n + 1) };
+      // Note: stepping past the last element could wrap the address space.
+      if self.count > 0 {
+        self.base = unsafe { self.base.add(n + 1) };
+      }
       out
     } else {
       self.count = 0;
@@ -305,6 +1382,256 @@ impl<T, R, W> core::iter::DoubleEndedIterator for VolBlockIter<T, R, W> {
   }
 }
 
+impl<T, R, W> core::iter::ExactSizeIterator for VolBlockIter<T, R, W> {}
+
+/// An iterator over addresses spaced a runtime number of bytes apart.
+///
+/// You will generally not construct types of this value yourself. Instead, you
+/// obtain them via the [`VolBlock::iter_step`](VolBlock::iter_step) method, or
+/// the [`VolStridedRegion::iter`](VolStridedRegion::iter) method.
+pub struct VolStepIter<T, R, W> {
+  pub(crate) base: VolAddress<T, R, W>,
+  pub(crate) count: usize,
+  /// The stride in bytes.
+  pub(crate) stride: usize,
+}
+
+impl<T, R, W> VolStepIter<T, R, W> {
+  /// The address `n` strides past the base.
+  #[inline]
+  unsafe fn at(&self, n: usize) -> VolAddress<T, R, W> {
+    self.base.cast::<u8>().add(n * self.stride).cast::<T>()
+  }
+}
+
+impl<T, R, W> Clone for VolStepIter<T, R, W> {
+  #[inline]
+  fn clone(&self) -> Self {
+    Self { base: self.base, count: self.count, stride: self.stride }
+  }
+}
+
+impl<T, R, W> core::iter::Iterator for VolStepIter<T, R, W> {
+  type Item = VolAddress<T, R, W>;
+
+  #[inline]
+  fn nth(&mut self, n: usize) -> Option<Self::Item> {
+    if n < self.count {
+      let out = Some(unsafe { self.at(n) });
+      self.count -= n + 1;
+      if self.count > 0 {
+        self.base = unsafe { self.at(n + 1) };
+      }
+      out
+    } else {
+      self.count = 0;
+      None
+    }
+  }
+
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    self.nth(0)
+  }
+
+  #[inline]
+  fn last(mut self) -> Option<Self::Item> {
+    if self.count > 0 {
+      self.nth(self.count - 1)
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (self.count, Some(self.count))
+  }
+
+  #[inline]
+  fn count(self) -> usize {
+    self.count
+  }
+}
+
+impl<T, R, W> core::iter::DoubleEndedIterator for VolStepIter<T, R, W> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    self.nth_back(0)
+  }
+
+  #[inline]
+  fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+    if n < self.count {
+      let out = Some(unsafe { self.at(self.count - (n + 1)) });
+      self.count -= n + 1;
+      out
+    } else {
+      self.count = 0;
+      None
+    }
+  }
+}
+
+impl<T, R, W> core::iter::ExactSizeIterator for VolStepIter<T, R, W> {}
+
+/// An iterator that gives the index of each address along with the address.
+///
+/// You will generally not construct types of this value yourself. Instead, you
+/// obtain them via the `iter_enumerated` method of [`VolBlock`],
+/// [`VolRegion`], or [`VolSeries`].
+#[derive(Clone)]
+pub struct VolEnumerate<I> {
+  pub(crate) iter: I,
+  pub(crate) index: usize,
+}
+
+impl<I: Iterator> core::iter::Iterator for VolEnumerate<I> {
+  type Item = (usize, I::Item);
+
+  #[inline]
+  fn nth(&mut self, n: usize) -> Option<Self::Item> {
+    let i = self.index + n;
+    let out = self.iter.nth(n)?;
+    self.index = i + 1;
+    Some((i, out))
+  }
+
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    self.nth(0)
+  }
+
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.iter.size_hint()
+  }
+}
+
+impl<I> core::iter::DoubleEndedIterator for VolEnumerate<I>
+where
+  I: DoubleEndedIterator + ExactSizeIterator,
+{
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    self.nth_back(0)
+  }
+
+  #[inline]
+  fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+    let out = self.iter.nth_back(n)?;
+    // The element given was just past the end of what's left.
+    Some((self.index + self.iter.len(), out))
+  }
+}
+
+impl<I: ExactSizeIterator> core::iter::ExactSizeIterator for VolEnumerate<I> {}
+
+/// An iterator over a volatile block in blocks of `N` elements.
+///
+/// You will generally not construct types of this value yourself. Instead, you
+/// obtain them via the [`VolBlock::chunks`](VolBlock::chunks) method.
+pub struct VolBlockChunks<T, R, W, const N: usize> {
+  pub(crate) iter: VolBlockIter<[T; N], R, W>,
+}
+
+impl<T, R, W, const N: usize> Clone for VolBlockChunks<T, R, W, N> {
+  #[inline]
+  fn clone(&self) -> Self {
+    Self { iter: self.iter.clone() }
+  }
+}
+
+impl<T, R, W, const N: usize> core::iter::Iterator
+  for VolBlockChunks<T, R, W, N>
+{
+  type Item = VolBlock<T, R, W, N>;
+
+  #[inline]
+  fn nth(&mut self, n: usize) -> Option<Self::Item> {
+    self.iter.nth(n).map(|va| VolBlock { base: unsafe { va.cast() } })
+  }
+
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    self.nth(0)
+  }
+
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.iter.size_hint()
+  }
+}
+
+impl<T, R, W, const N: usize> core::iter::DoubleEndedIterator
+  for VolBlockChunks<T, R, W, N>
+{
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    self.nth_back(0)
+  }
+
+  #[inline]
+  fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+    self.iter.nth_back(n).map(|va| VolBlock { base: unsafe { va.cast() } })
+  }
+}
+
+impl<T, R, W, const N: usize> core::iter::ExactSizeIterator
+  for VolBlockChunks<T, R, W, N>
+{
+}
+
+/// An iterator that reads the values of a block or region from back to front.
+///
+/// You will generally not construct types of this value yourself. Instead, you
+/// obtain them via the `iter_rev_values` method of [`VolBlock`] or
+/// [`VolRegion`].
+pub struct VolRevValues<T, W> {
+  pub(crate) iter: VolBlockIter<T, Safe, W>,
+}
+
+impl<T, W> Clone for VolRevValues<T, W> {
+  #[inline]
+  fn clone(&self) -> Self {
+    Self { iter: self.iter.clone() }
+  }
+}
+
+impl<T, W> core::iter::Iterator for VolRevValues<T, W>
+where
+  T: Copy,
+{
+  type Item = T;
+
+  #[inline]
+  fn next(&mut self) -> Option<T> {
+    self.iter.next_back().map(|va| va.read())
+  }
+
+  #[inline]
+  fn nth(&mut self, n: usize) -> Option<T> {
+    self.iter.nth_back(n).map(|va| va.read())
+  }
+
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.iter.size_hint()
+  }
+}
+
+impl<T, W> core::iter::DoubleEndedIterator for VolRevValues<T, W>
+where
+  T: Copy,
+{
+  #[inline]
+  fn next_back(&mut self) -> Option<T> {
+    self.iter.next().map(|va| va.read())
+  }
+}
+
+impl<T, W> core::iter::ExactSizeIterator for VolRevValues<T, W> where T: Copy {}
+
 #[test]
 fn test_impl_Iterator_for_VolBlockIter() {
   let i: VolBlockIter<u16, (), ()> = VolBlockIter {
@@ -358,6 +1685,19 @@ fn test_impl_Iterator_for_VolBlockIter() {
   assert_eq!(i_c.nth(4), None);
 }
 
+#[test]
+fn test_volblockiter_eq_debug() {
+  extern crate std;
+  use std::format;
+  let block: VolBlock<u16, Safe, Safe, 4> = unsafe { VolBlock::new(0x1000) };
+  let mut iter = block.iter();
+  assert_eq!(iter, block.iter_range(..));
+  iter.next();
+  assert_eq!(iter, block.iter_range(1..));
+  assert_ne!(iter, block.iter_range(1..3));
+  assert!(format!("{:?}", iter).ends_with("{ base: 0x1002, count: 3 }"));
+}
+
 #[test]
 fn test_impl_DoubleEndedIterator_for_VolBlockIter() {
   let i: VolBlockIter<u16, (), ()> = VolBlockIter {
@@ -401,3 +1741,38 @@ fn test_impl_DoubleEndedIterator_for_VolBlockIter() {
   assert_eq!(i_c.nth_back(4), None);
   assert_eq!(i_c.nth_back(4), None);
 }
+
+#[test]
+fn test_volblock_rev_values() {
+  let mut hw = [1_u8, 2, 3, 4];
+  let block: VolBlock<u8, Safe, (), 4> =
+    unsafe { VolBlock::new(hw.as_mut_ptr() as usize) };
+  let mut iter = block.iter_rev_values();
+  assert_eq!(iter.len(), 4);
+  assert_eq!(iter.next(), Some(4));
+  assert_eq!(iter.next_back(), Some(1));
+  assert_eq!(iter.len(), 2);
+  let mut buffer = [0; 4];
+  block.read_to_slice_rev(&mut buffer);
+  assert_eq!(buffer, [4, 3, 2, 1]);
+}
+
+#[test]
+fn test_volblock_index_unchecked() {
+  let block: VolBlock<u16, Safe, Safe, 4> = unsafe { VolBlock::new(0x1000) };
+  assert_eq!(unsafe { block.index_unchecked(3) }, block.index(3));
+  assert_eq!(unsafe { block.get_unchecked(2) }, block.index(2));
+  assert_eq!(unsafe { block.index_unchecked(0) }.as_usize(), 0x1000);
+}
+
+#[test]
+fn test_volblock_iter_top_of_address_space() {
+  let block: VolBlock<u32, Safe, Safe, 4> =
+    unsafe { VolBlock::new(usize::MAX - core::mem::size_of::<u32>() * 4 + 1) };
+  let mut iter = block.iter();
+  assert_eq!(iter.next(), Some(block.index(0)));
+  assert_eq!(iter.nth(1), Some(block.index(2)));
+  assert_eq!(iter.next(), Some(block.index(3)));
+  assert_eq!(iter.next(), None);
+  assert_eq!(block.iter().last(), Some(block.index(3)));
+}
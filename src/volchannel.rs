@@ -0,0 +1,154 @@
+use super::*;
+
+/// A one-way message channel between two CPUs, using shared memory.
+///
+/// Systems with more than one CPU (eg: the ARM7 and ARM9 of the NDS, or the
+/// two cores of the RP2040) often pass messages through some shared memory.
+/// This type bundles the three parts of such a channel:
+/// * A `command` location, where the sender puts a non-zero command value to
+///   ring the "doorbell". The receiver sets it back to 0 once the message has
+///   been taken.
+/// * A `status` location, where the receiver puts the last command value that
+///   it took.
+/// * A `payload` region, for the message data.
+///
+/// Both CPUs make a `VolChannel` with the same locations. One side only calls
+/// [`send`](Self::send), and the other side only calls
+/// [`try_recv`](Self::try_recv). Fences are used so that the payload is
+/// always fully written before the command is seen, and fully read before the
+/// command is cleared. For messages in both directions, use two channels.
+pub struct VolChannel<T> {
+  command: VolAddress<u32, Safe, Safe>,
+  status: VolAddress<u32, Safe, Safe>,
+  payload: VolRegion<T, Safe, Safe>,
+}
+
+impl<T> Clone for VolChannel<T> {
+  #[inline]
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+impl<T> Copy for VolChannel<T> {}
+
+impl<T> VolChannel<T> {
+  /// Makes a channel from its parts.
+  #[inline]
+  #[must_use]
+  pub const fn new(
+    command: VolAddress<u32, Safe, Safe>, status: VolAddress<u32, Safe, Safe>,
+    payload: VolRegion<T, Safe, Safe>,
+  ) -> Self {
+    Self { command, status, payload }
+  }
+
+  /// The payload region.
+  #[inline]
+  #[must_use]
+  pub const fn payload(self) -> VolRegion<T, Safe, Safe> {
+    self.payload
+  }
+
+  /// If a message has been sent but not yet taken by the receiver.
+  #[inline]
+  #[must_use]
+  pub fn is_busy(self) -> bool {
+    self.command.read() != 0
+  }
+
+  /// The last command value that the receiver took, or 0 if none has been
+  /// taken yet.
+  #[inline]
+  #[must_use]
+  pub fn status(self) -> u32 {
+    self.status.read()
+  }
+}
+
+impl<T: Copy> VolChannel<T> {
+  /// Sends a message.
+  ///
+  /// The payload data is written to the start of the payload region, then the
+  /// command is written. If the previous message hasn't been taken yet then
+  /// nothing is written and this gives `false`.
+  ///
+  /// ## Panics
+  /// * If `command` is 0.
+  /// * If the data is longer than the payload region.
+  #[inline]
+  #[track_caller]
+  pub fn send(self, command: u32, data: &[T]) -> bool {
+    assert!(command != 0);
+    assert!(data.len() <= self.payload.len());
+    if self.is_busy() {
+      return false;
+    }
+    VolRegion { addr: self.payload.addr, len: data.len() }
+      .write_from_slice(data);
+    fence(Ordering::Release);
+    self.command.write(command);
+    true
+  }
+
+  /// Takes the waiting message, if any.
+  ///
+  /// The start of the payload region is read into the buffer, then the
+  /// command value is put into the status location, and the command location
+  /// is cleared so that the sender can send again.
+  ///
+  /// Gives the command value, or `None` if no message is waiting.
+  ///
+  /// ## Panics
+  /// * If the buffer is longer than the payload region.
+  #[inline]
+  #[track_caller]
+  pub fn try_recv(self, buffer: &mut [T]) -> Option<u32> {
+    assert!(buffer.len() <= self.payload.len());
+    let command = self.command.read();
+    if command == 0 {
+      return None;
+    }
+    fence(Ordering::Acquire);
+    VolRegion { addr: self.payload.addr, len: buffer.len() }
+      .read_to_slice(buffer);
+    fence(Ordering::Release);
+    self.status.write(command);
+    self.command.write(0);
+    Some(command)
+  }
+}
+
+impl<T> core::fmt::Debug for VolChannel<T> {
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("VolChannel")
+      .field("command", &self.command)
+      .field("status", &self.status)
+      .field("payload", &self.payload)
+      .finish()
+  }
+}
+
+#[test]
+fn test_volchannel() {
+  let mut hw = [0_u32; 2 + 4];
+  let base: VolAddress<u32, Safe, Safe> =
+    unsafe { VolAddress::new(hw.as_mut_ptr() as usize) };
+  let channel = unsafe {
+    VolChannel::new(
+      base,
+      base.add(1),
+      VolRegion::from_raw_parts(base.add(2), 4),
+    )
+  };
+  let mut buffer = [0_u32; 2];
+  assert_eq!(channel.try_recv(&mut buffer), None);
+  assert!(channel.send(7, &[1, 2]));
+  assert!(channel.is_busy());
+  assert!(!channel.send(8, &[3]));
+  assert_eq!(channel.try_recv(&mut buffer), Some(7));
+  assert_eq!(buffer, [1, 2]);
+  assert_eq!(channel.status(), 7);
+  assert!(!channel.is_busy());
+  assert_eq!(channel.try_recv(&mut buffer), None);
+}
@@ -0,0 +1,221 @@
+use super::*;
+
+/// A cursor over a block, series, or region.
+///
+/// The cursor holds a current position, and each `read_next` or `write_next`
+/// accesses the element at that position and then advances it. This is useful
+/// for protocol-style code that consumes or produces a volatile buffer in
+/// order, without needing to manually track the index.
+///
+/// You will generally make a cursor with one of the `from_` constructors.
+pub struct VolCursor<T, R, W> {
+  base: VolAddress<T, R, W>,
+  len: usize,
+  stride: usize,
+  pos: usize,
+}
+
+impl<T, R, W> Clone for VolCursor<T, R, W> {
+  #[inline]
+  fn clone(&self) -> Self {
+    Self { base: self.base, len: self.len, stride: self.stride, pos: self.pos }
+  }
+}
+
+impl<T, R, W> VolCursor<T, R, W> {
+  /// Makes a cursor at the start of the block.
+  #[inline]
+  #[must_use]
+  pub const fn from_block<const C: usize>(block: VolBlock<T, R, W, C>) -> Self {
+    Self { base: block.base, len: C, stride: core::mem::size_of::<T>(), pos: 0 }
+  }
+
+  /// Makes a cursor at the start of the series.
+  #[inline]
+  #[must_use]
+  pub const fn from_series<const C: usize, const S: usize>(
+    series: VolSeries<T, R, W, C, S>,
+  ) -> Self {
+    Self { base: series.base, len: C, stride: S, pos: 0 }
+  }
+
+  /// Makes a cursor at the start of the region.
+  #[inline]
+  #[must_use]
+  pub const fn from_region(region: VolRegion<T, R, W>) -> Self {
+    Self {
+      base: region.addr,
+      len: region.len,
+      stride: core::mem::size_of::<T>(),
+      pos: 0,
+    }
+  }
+
+  /// The total number of elements the cursor covers.
+  #[inline]
+  #[must_use]
+  #[allow(clippy::len_without_is_empty)]
+  pub const fn len(&self) -> usize {
+    self.len
+  }
+
+  /// The current position of the cursor.
+  #[inline]
+  #[must_use]
+  pub const fn position(&self) -> usize {
+    self.pos
+  }
+
+  /// The number of elements from the current position to the end.
+  #[inline]
+  #[must_use]
+  pub const fn remaining(&self) -> usize {
+    self.len - self.pos
+  }
+
+  /// Moves the cursor to the position given.
+  ///
+  /// Seeking to `len` is allowed, leaving the cursor at the end.
+  ///
+  /// ## Panics
+  /// * If the position is past the end.
+  #[inline]
+  #[track_caller]
+  pub fn seek(&mut self, pos: usize) {
+    assert!(pos <= self.len);
+    self.pos = pos;
+  }
+
+  /// Advances the cursor by `n` elements without accessing them.
+  ///
+  /// ## Panics
+  /// * If this would move the cursor past the end.
+  #[inline]
+  #[track_caller]
+  pub fn skip(&mut self, n: usize) {
+    assert!(n <= self.remaining());
+    self.pos += n;
+  }
+
+  /// Gets the address at the current position and advances the cursor.
+  ///
+  /// Gives `None` if the cursor is at the end.
+  #[inline]
+  pub fn next_address(&mut self) -> Option<VolAddress<T, R, W>> {
+    if self.pos < self.len {
+      let out = unsafe {
+        self.base.cast::<u8>().add(self.pos * self.stride).cast::<T>()
+      };
+      self.pos += 1;
+      Some(out)
+    } else {
+      None
+    }
+  }
+}
+
+impl<T, W> VolCursor<T, Safe, W>
+where
+  T: Copy,
+{
+  /// Volatile reads the value at the current position and advances the cursor.
+  ///
+  /// Gives `None` if the cursor is at the end.
+  #[inline]
+  pub fn read_next(&mut self) -> Option<T> {
+    self.next_address().map(|va| va.read())
+  }
+}
+impl<T, W> VolCursor<T, Unsafe, W>
+where
+  T: Copy,
+{
+  /// Volatile reads the value at the current position and advances the cursor.
+  ///
+  /// Gives `None` if the cursor is at the end.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn read_next(&mut self) -> Option<T> {
+    self.next_address().map(|va| va.read())
+  }
+}
+
+impl<T, R> VolCursor<T, R, Safe>
+where
+  T: Copy,
+{
+  /// Volatile writes the value at the current position and advances the
+  /// cursor.
+  ///
+  /// Gives `false` (without writing) if the cursor is at the end.
+  #[inline]
+  pub fn write_next(&mut self, t: T) -> bool {
+    match self.next_address() {
+      Some(va) => {
+        va.write(t);
+        true
+      }
+      None => false,
+    }
+  }
+}
+impl<T, R> VolCursor<T, R, Unsafe>
+where
+  T: Copy,
+{
+  /// Volatile writes the value at the current position and advances the
+  /// cursor.
+  ///
+  /// Gives `false` (without writing) if the cursor is at the end.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn write_next(&mut self, t: T) -> bool {
+    match self.next_address() {
+      Some(va) => {
+        va.write(t);
+        true
+      }
+      None => false,
+    }
+  }
+}
+
+impl<T, R, W> core::fmt::Debug for VolCursor<T, R, W> {
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "VolCursor<{elem_ty}, r{readability}, w{writeability}>({address:#X}, len: {len}, stride: {stride:#X}, pos: {pos})",
+      elem_ty = core::any::type_name::<T>(),
+      readability=core::any::type_name::<R>(),
+      writeability=core::any::type_name::<W>(),
+      address=self.base.as_usize(),
+      len=self.len,
+      stride=self.stride,
+      pos=self.pos,
+    )
+  }
+}
+
+#[test]
+fn test_volcursor() {
+  let mut hw = [0_u8; 6];
+  let series: VolSeries<u8, Safe, Safe, 3, 2> =
+    unsafe { VolSeries::new(hw.as_mut_ptr() as usize) };
+  let mut c = VolCursor::from_series(series);
+  assert!(c.write_next(1));
+  c.skip(1);
+  assert!(c.write_next(3));
+  assert!(!c.write_next(4));
+  assert_eq!(c.remaining(), 0);
+  c.seek(0);
+  assert_eq!(c.read_next(), Some(1));
+  assert_eq!(c.read_next(), Some(0));
+  assert_eq!(c.read_next(), Some(3));
+  assert_eq!(c.read_next(), None);
+  assert_eq!(hw, [1, 0, 0, 0, 3, 0]);
+}
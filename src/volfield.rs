@@ -0,0 +1,281 @@
+use super::*;
+
+/// A type that can be converted to and from the bits of a [`VolField`].
+///
+/// This is implemented for `bool`, `u8`, `u16`, and `u32`. You can also
+/// implement it for your own types, such as an enum of the field's possible
+/// values. When not every bit pattern is a valid value, set `Read` to a
+/// `Result` so that decoding can fail:
+///
+/// ```
+/// # use voladdress::*;
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// #[repr(u8)]
+/// pub enum Mode {
+///   Off = 0,
+///   Slow = 1,
+///   Fast = 2,
+/// }
+/// impl FieldBits for Mode {
+///   type Read = Result<Mode, u8>;
+///   fn to_bits(self) -> u32 {
+///     self as u32
+///   }
+///   fn from_bits(bits: u32) -> Result<Mode, u8> {
+///     match bits {
+///       0 => Ok(Mode::Off),
+///       1 => Ok(Mode::Slow),
+///       2 => Ok(Mode::Fast),
+///       other => Err(other as u8),
+///     }
+///   }
+/// }
+///
+/// let mut hw = 0_u16;
+/// let reg: VolAddress<u16, Safe, Safe> =
+///   unsafe { VolAddress::new(&mut hw as *mut u16 as usize) };
+/// let mode: VolField<u16, Safe, Safe, 0b11, 2, Mode> = VolField::new(reg);
+/// mode.write_field(Mode::Fast);
+/// assert_eq!(mode.read_field(), Ok(Mode::Fast));
+/// reg.write(0b1100);
+/// assert_eq!(mode.read_field(), Err(3));
+/// ```
+pub trait FieldBits: Copy {
+  /// The type given when reading the field.
+  ///
+  /// This is `Self` when every bit pattern is a valid value.
+  type Read;
+  /// Converts the value into bits.
+  fn to_bits(self) -> u32;
+  /// Converts bits into a value.
+  ///
+  /// Any bits that don't fit in the type are ignored.
+  fn from_bits(bits: u32) -> Self::Read;
+}
+
+impl FieldBits for bool {
+  type Read = bool;
+  #[inline]
+  fn to_bits(self) -> u32 {
+    u32::from(self)
+  }
+  #[inline]
+  fn from_bits(bits: u32) -> Self {
+    bits != 0
+  }
+}
+
+macro_rules! impl_field_bits {
+  ($($t:ty),*) => {
+    $(
+      impl FieldBits for $t {
+        type Read = $t;
+        #[inline]
+        fn to_bits(self) -> u32 {
+          u32::from(self)
+        }
+        #[inline]
+        fn from_bits(bits: u32) -> Self {
+          bits as $t
+        }
+      }
+    )*
+  };
+}
+impl_field_bits!(u8, u16, u32);
+
+/// A single named field within a register.
+///
+/// The field is the `MASK` bits of the register, after shifting the register
+/// right by `SHIFT`. For example, bits 8 through 10 of a register would have a
+/// `MASK` of `0b111` and a `SHIFT` of 8.
+///
+/// Memory map crates can declare a `const` of this type for each field of a
+/// register, so that users don't need to deal with raw masks and shifts.
+///
+/// ```
+/// # use voladdress::*;
+/// let mut hw = 0_u16;
+/// let reg: VolAddress<u16, Safe, Safe> =
+///   unsafe { VolAddress::new(&mut hw as *mut u16 as usize) };
+/// let mode: VolField<u16, Safe, Safe, 0b111, 0, u8> = VolField::new(reg);
+/// let enabled: VolField<u16, Safe, Safe, 1, 8, bool> = VolField::new(reg);
+/// mode.write_field(3);
+/// enabled.write_field(true);
+/// assert_eq!(reg.read(), 0x0103);
+/// assert_eq!(mode.read_field(), 3);
+/// ```
+///
+/// ## Generic Parameters
+/// * `T` / `R` / `W`: As with the [`VolAddress`] of the register.
+/// * `MASK`: The bits of the field, before shifting.
+/// * `SHIFT`: How far the field is from the lowest bit of the register.
+/// * `V`: The type of the field's value.
+#[repr(transparent)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VolField<T, R, W, const MASK: u32, const SHIFT: u32, V> {
+  address: VolAddress<T, R, W>,
+  value: PhantomData<V>,
+}
+
+impl<T, R, W, const MASK: u32, const SHIFT: u32, V> Clone
+  for VolField<T, R, W, MASK, SHIFT, V>
+{
+  #[inline]
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+impl<T, R, W, const MASK: u32, const SHIFT: u32, V> Copy
+  for VolField<T, R, W, MASK, SHIFT, V>
+{
+}
+
+impl<T, R, W, const MASK: u32, const SHIFT: u32, V>
+  VolField<T, R, W, MASK, SHIFT, V>
+{
+  /// Makes the field of the given register.
+  #[inline]
+  #[must_use]
+  pub const fn new(address: VolAddress<T, R, W>) -> Self {
+    Self { address, value: PhantomData }
+  }
+
+  /// The register that holds this field.
+  #[inline]
+  #[must_use]
+  pub const fn address(self) -> VolAddress<T, R, W> {
+    self.address
+  }
+}
+
+impl<T, R, W, const MASK: u32, const SHIFT: u32, V>
+  VolField<T, R, W, MASK, SHIFT, V>
+where
+  T: FieldBits<Read = T>,
+  V: FieldBits,
+{
+  /// Gets the field's value out of a register value.
+  #[inline]
+  #[must_use]
+  fn extract(t: T) -> V::Read {
+    V::from_bits((t.to_bits() >> SHIFT) & MASK)
+  }
+
+  /// Puts the field's value into a register value.
+  #[inline]
+  #[must_use]
+  fn insert(t: T, v: V) -> T {
+    let bits = t.to_bits() & !(MASK << SHIFT);
+    T::from_bits(bits | ((v.to_bits() & MASK) << SHIFT))
+  }
+}
+
+impl<T, W, const MASK: u32, const SHIFT: u32, V>
+  VolField<T, Safe, W, MASK, SHIFT, V>
+where
+  T: FieldBits<Read = T>,
+  V: FieldBits,
+{
+  /// Volatile reads the register, and gives the field's value.
+  #[inline]
+  pub fn read_field(self) -> V::Read {
+    Self::extract(self.address.read())
+  }
+}
+impl<T, W, const MASK: u32, const SHIFT: u32, V>
+  VolField<T, Unsafe, W, MASK, SHIFT, V>
+where
+  T: FieldBits<Read = T>,
+  V: FieldBits,
+{
+  /// Volatile reads the register, and gives the field's value.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn read_field(self) -> V::Read {
+    Self::extract(self.address.read())
+  }
+}
+
+impl<T, const MASK: u32, const SHIFT: u32, V>
+  VolField<T, Safe, Safe, MASK, SHIFT, V>
+where
+  T: FieldBits<Read = T>,
+  V: FieldBits,
+{
+  /// Reads the register, changes just this field, and writes the register
+  /// back.
+  #[inline]
+  pub fn write_field(self, v: V) {
+    self.address.apply(|t| *t = Self::insert(*t, v))
+  }
+
+  /// Reads the register, passes the field's value through the function, and
+  /// writes the register back with the new field value.
+  #[inline]
+  pub fn modify_field<F: FnOnce(V::Read) -> V>(self, f: F) {
+    self.address.apply(|t| *t = Self::insert(*t, f(Self::extract(*t))))
+  }
+}
+impl<T, R, W, const MASK: u32, const SHIFT: u32, V>
+  VolField<T, R, W, MASK, SHIFT, V>
+where
+  T: FieldBits<Read = T>,
+  V: FieldBits,
+  (R, W): UnsafeReadWrite,
+{
+  /// Reads the register, changes just this field, and writes the register
+  /// back.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading/writing this address depend on the device.
+  ///   Consult your hardware manual.
+  #[inline]
+  pub unsafe fn write_field(self, v: V) {
+    self.address.apply(|t| *t = Self::insert(*t, v))
+  }
+
+  /// Reads the register, passes the field's value through the function, and
+  /// writes the register back with the new field value.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading/writing this address depend on the device.
+  ///   Consult your hardware manual.
+  #[inline]
+  pub unsafe fn modify_field<F: FnOnce(V::Read) -> V>(self, f: F) {
+    self.address.apply(|t| *t = Self::insert(*t, f(Self::extract(*t))))
+  }
+}
+
+impl<T, R, W, const MASK: u32, const SHIFT: u32, V> core::fmt::Debug
+  for VolField<T, R, W, MASK, SHIFT, V>
+{
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "VolField<{elem_ty}, r{readability}, w{writeability}, {mask:#X}, {shift}, {value_ty}>(0x{address:#X})",
+      elem_ty = core::any::type_name::<T>(),
+      readability=core::any::type_name::<R>(),
+      writeability=core::any::type_name::<W>(),
+      mask=MASK,
+      shift=SHIFT,
+      value_ty=core::any::type_name::<V>(),
+      address=self.address.as_usize())
+  }
+}
+
+#[test]
+fn test_volfield() {
+  let mut hw = 0xFFFF_u16;
+  let reg: VolAddress<u16, Safe, Unsafe> =
+    unsafe { VolAddress::new(&mut hw as *mut u16 as usize) };
+  let field: VolField<u16, Safe, Unsafe, 0b111, 4, u8> = VolField::new(reg);
+  assert_eq!(field.read_field(), 0b111);
+  unsafe { field.write_field(0b1010) };
+  assert_eq!(hw, 0xFFAF);
+  unsafe { field.modify_field(|v| v + 1) };
+  assert_eq!(field.read_field(), 0b011);
+  assert_eq!(hw, 0xFFBF);
+}
@@ -1,4 +1,4 @@
-use crate::{VolAddress, VolBlock};
+use crate::{Safe, Unsafe, VolAddress, VolBlock};
 
 /// A 2D version of [`VolBlock`], with a const generic `WIDTH` and `HEIGHT`.
 ///
@@ -132,6 +132,49 @@ impl<T, R, W, const WIDTH: usize, const HEIGHT: usize>
     unsafe { self.base.add(x + y * WIDTH) }
   }
 
+  /// Indexes to the `(x,y)` given without a bounds check.
+  ///
+  /// This is for hot loops that have already checked their indexes, so that
+  /// they don't pay for a second check or carry a panic path.
+  ///
+  /// ## Safety
+  /// * The coordinates must be in bounds.
+  #[inline]
+  #[must_use]
+  pub const unsafe fn index_unchecked(
+    self, x: usize, y: usize,
+  ) -> VolAddress<T, R, W> {
+    self.base.add(x + y * WIDTH)
+  }
+
+  /// Gets the address of the `(x,y)` given without a bounds check.
+  ///
+  /// This is the same as [`index_unchecked`](Self::index_unchecked), named to
+  /// match the slice method.
+  ///
+  /// ## Safety
+  /// * The coordinates must be in bounds.
+  #[inline]
+  #[must_use]
+  pub const unsafe fn get_unchecked(
+    self, x: usize, y: usize,
+  ) -> VolAddress<T, R, W> {
+    self.index_unchecked(x, y)
+  }
+
+  /// Indexes the address of the position given.
+  ///
+  /// The position was already checked when it was made, so this doesn't need
+  /// any bounds checks.
+  #[inline]
+  #[must_use]
+  pub const fn index_pos(
+    self, pos: GridPos<WIDTH, HEIGHT>,
+  ) -> VolAddress<T, R, W> {
+    // safety: `GridPos` is always in bounds
+    unsafe { self.base.add(pos.x + pos.y * WIDTH) }
+  }
+
   /// Get a single row of the grid as a [`VolBlock`].
   #[inline]
   #[must_use]
@@ -148,6 +191,41 @@ impl<T, R, W, const WIDTH: usize, const HEIGHT: usize>
     }
   }
 
+  /// Iterates over the outer edge of the grid.
+  ///
+  /// This is the same as [`iter_border`](Self::iter_border) with a thickness
+  /// of 1.
+  #[inline]
+  pub fn iter_edge(self) -> impl Iterator<Item = VolAddress<T, R, W>> {
+    self.iter_border(1)
+  }
+
+  /// Iterates over a border of the given thickness around the grid.
+  ///
+  /// The border is the top and bottom `thickness` rows, plus the left and
+  /// right `thickness` columns of the rows between them. Each cell is given
+  /// exactly once (including the corners), going left to right within each
+  /// row, and then top to bottom. If the border is thick enough to cover the
+  /// whole grid then every cell is given.
+  #[inline]
+  pub fn iter_border(
+    self, thickness: usize,
+  ) -> impl Iterator<Item = VolAddress<T, R, W>> {
+    (0..HEIGHT).flat_map(move |y| {
+      let inner = y >= thickness && y < HEIGHT.saturating_sub(thickness);
+      let (left_end, right_start) = if inner {
+        let left_end = thickness.min(WIDTH);
+        (left_end, WIDTH.saturating_sub(thickness).max(left_end))
+      } else {
+        (WIDTH, WIDTH)
+      };
+      (0..left_end)
+        .chain(right_start..WIDTH)
+        // SAFETY: `x < WIDTH` and `y < HEIGHT`
+        .map(move |x| unsafe { self.base.add(x + y * WIDTH) })
+    })
+  }
+
   /// Converts the `VolGrid2d` the `usize` for the start of the grid.
   #[inline]
   #[must_use]
@@ -155,3 +233,202 @@ impl<T, R, W, const WIDTH: usize, const HEIGHT: usize>
     self.base.address.get()
   }
 }
+
+/// An `(x,y)` position that's known to be within a `WIDTH` by `HEIGHT` grid.
+///
+/// The position is checked once when it's made, and then can be used with
+/// [`VolGrid2d::index_pos`] as many times as you like with no further bounds
+/// checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GridPos<const WIDTH: usize, const HEIGHT: usize> {
+  x: usize,
+  y: usize,
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> GridPos<WIDTH, HEIGHT> {
+  /// Makes a position.
+  ///
+  /// ## Panics
+  /// * If either coordinate is out of bounds. When used to initialize a
+  ///   `const` this check happens at compile time.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub const fn new(x: usize, y: usize) -> Self {
+    assert!(x < WIDTH);
+    assert!(y < HEIGHT);
+    Self { x, y }
+  }
+
+  /// Makes a position, or gives `None` if either coordinate is out of bounds.
+  #[inline]
+  #[must_use]
+  pub const fn try_new(x: usize, y: usize) -> Option<Self> {
+    if x < WIDTH && y < HEIGHT {
+      Some(Self { x, y })
+    } else {
+      None
+    }
+  }
+
+  /// Makes a position without checking the coordinates.
+  ///
+  /// ## Safety
+  /// * `x` must be less than `WIDTH`, and `y` must be less than `HEIGHT`.
+  #[inline]
+  #[must_use]
+  pub const unsafe fn new_unchecked(x: usize, y: usize) -> Self {
+    Self { x, y }
+  }
+
+  /// The x coordinate.
+  #[inline]
+  #[must_use]
+  pub const fn x(self) -> usize {
+    self.x
+  }
+
+  /// The y coordinate.
+  #[inline]
+  #[must_use]
+  pub const fn y(self) -> usize {
+    self.y
+  }
+}
+
+impl<T, R, const WIDTH: usize, const HEIGHT: usize>
+  VolGrid2d<T, R, Safe, WIDTH, HEIGHT>
+where
+  T: Copy,
+{
+  /// Writes converted values into a rectangle of the grid.
+  ///
+  /// The rectangle covers the `xs` columns of the `ys` rows. Each value from
+  /// the iterator is passed through the conversion function and then written,
+  /// going left to right within each row, and then top to bottom. This lets
+  /// you blit from some other color format without first converting into a
+  /// staging buffer.
+  ///
+  /// If the iterator runs out early then the rest of the rectangle is left
+  /// unchanged. Any extra items in the iterator are not used.
+  ///
+  /// ## Panics
+  /// * If either range extends past the edge of the grid.
+  #[inline]
+  #[track_caller]
+  pub fn write_rect_with<U, I, F>(
+    self, xs: core::ops::Range<usize>, ys: core::ops::Range<usize>, iter: I,
+    mut f: F,
+  ) where
+    I: IntoIterator<Item = U>,
+    F: FnMut(U) -> T,
+  {
+    assert!(xs.end <= WIDTH);
+    assert!(ys.end <= HEIGHT);
+    let mut iter = iter.into_iter();
+    for y in ys {
+      for x in xs.clone() {
+        match iter.next() {
+          Some(u) => unsafe { self.base.add(x + y * WIDTH) }.write(f(u)),
+          None => return,
+        }
+      }
+    }
+  }
+}
+impl<T, R, const WIDTH: usize, const HEIGHT: usize>
+  VolGrid2d<T, R, Unsafe, WIDTH, HEIGHT>
+where
+  T: Copy,
+{
+  /// Writes converted values into a rectangle of the grid.
+  ///
+  /// The rectangle covers the `xs` columns of the `ys` rows. Each value from
+  /// the iterator is passed through the conversion function and then written,
+  /// going left to right within each row, and then top to bottom. This lets
+  /// you blit from some other color format without first converting into a
+  /// staging buffer.
+  ///
+  /// If the iterator runs out early then the rest of the rectangle is left
+  /// unchanged. Any extra items in the iterator are not used.
+  ///
+  /// ## Panics
+  /// * If either range extends past the edge of the grid.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  #[track_caller]
+  pub unsafe fn write_rect_with<U, I, F>(
+    self, xs: core::ops::Range<usize>, ys: core::ops::Range<usize>, iter: I,
+    mut f: F,
+  ) where
+    I: IntoIterator<Item = U>,
+    F: FnMut(U) -> T,
+  {
+    assert!(xs.end <= WIDTH);
+    assert!(ys.end <= HEIGHT);
+    let mut iter = iter.into_iter();
+    for y in ys {
+      for x in xs.clone() {
+        match iter.next() {
+          Some(u) => self.base.add(x + y * WIDTH).write(f(u)),
+          None => return,
+        }
+      }
+    }
+  }
+}
+
+#[test]
+fn test_volgrid2d_write_rect_with() {
+  let mut hw = [0_u16; 4 * 3];
+  let grid: VolGrid2d<u16, (), Safe, 4, 3> =
+    unsafe { VolGrid2d::new(hw.as_mut_ptr() as usize) };
+  grid.write_rect_with(1..3, 1..3, [1_u8, 2, 3, 4], |c| u16::from(c) * 10);
+  assert_eq!(hw, [0, 0, 0, 0, 0, 10, 20, 0, 0, 30, 40, 0]);
+  //
+  grid.write_rect_with(0..4, 0..1, [7_u8], u16::from);
+  assert_eq!(&hw[..4], &[7, 0, 0, 0]);
+}
+
+#[test]
+fn test_volgrid2d_iter_border() {
+  let mut hw = [0_u8; 5 * 4];
+  let grid: VolGrid2d<u8, Safe, Safe, 5, 4> =
+    unsafe { VolGrid2d::new(hw.as_mut_ptr() as usize) };
+  grid.iter_edge().for_each(|a| a.write(a.read() + 1));
+  assert_eq!(
+    hw,
+    [
+      1, 1, 1, 1, 1, //
+      1, 0, 0, 0, 1, //
+      1, 0, 0, 0, 1, //
+      1, 1, 1, 1, 1, //
+    ]
+  );
+  assert_eq!(grid.iter_edge().count(), 14);
+  assert_eq!(grid.iter_border(2).count(), 20);
+  assert_eq!(grid.iter_border(9).count(), 20);
+  assert_eq!(grid.iter_border(0).count(), 0);
+}
+
+#[test]
+fn test_volgrid2d_index_pos() {
+  const CENTER: GridPos<4, 3> = GridPos::new(2, 1);
+  let grid: VolGrid2d<u16, Safe, Safe, 4, 3> =
+    unsafe { VolGrid2d::new(0x1000) };
+  assert_eq!(grid.index_pos(CENTER), grid.index(2, 1));
+  assert_eq!(GridPos::<4, 3>::try_new(3, 2), Some(GridPos::new(3, 2)));
+  assert!(GridPos::<4, 3>::try_new(4, 0).is_none());
+  assert!(GridPos::<4, 3>::try_new(0, 3).is_none());
+}
+
+#[test]
+fn test_volgrid2d_index_unchecked() {
+  let grid: VolGrid2d<u16, Safe, Safe, 4, 3> =
+    unsafe { VolGrid2d::new(0x1000) };
+  assert_eq!(unsafe { grid.index_unchecked(3, 2) }, grid.index(3, 2));
+  assert_eq!(unsafe { grid.get_unchecked(1, 1) }, grid.index(1, 1));
+}
@@ -0,0 +1,226 @@
+use crate::{Safe, Unsafe, VolGrid2d};
+use core::marker::PhantomData;
+
+/// A 16-bit pixel format.
+///
+/// Color channels are given as 8-bit values. When packing, the low bits of
+/// each channel that don't fit in the format are dropped. When unpacking, each
+/// channel is scaled back up to the full 8-bit range.
+pub trait PixelFormat {
+  /// Packs the color channels into a pixel value.
+  fn pack(r: u8, g: u8, b: u8) -> u16;
+
+  /// Unpacks a pixel value into its color channels.
+  fn unpack(pixel: u16) -> [u8; 3];
+}
+
+/// Scales a 5-bit channel up to 8 bits.
+#[inline]
+const fn expand5(c: u16) -> u8 {
+  let c = (c & 0b1_1111) as u8;
+  (c << 3) | (c >> 2)
+}
+
+/// Scales a 6-bit channel up to 8 bits.
+#[inline]
+const fn expand6(c: u16) -> u8 {
+  let c = (c & 0b11_1111) as u8;
+  (c << 2) | (c >> 4)
+}
+
+/// 5 bits per channel: red in bits 10-14, green in 5-9, and blue in 0-4.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Rgb555;
+impl PixelFormat for Rgb555 {
+  #[inline]
+  fn pack(r: u8, g: u8, b: u8) -> u16 {
+    (u16::from(r >> 3) << 10) | (u16::from(g >> 3) << 5) | u16::from(b >> 3)
+  }
+  #[inline]
+  fn unpack(pixel: u16) -> [u8; 3] {
+    [expand5(pixel >> 10), expand5(pixel >> 5), expand5(pixel)]
+  }
+}
+
+/// 5 bits per channel: blue in bits 10-14, green in 5-9, and red in 0-4.
+///
+/// This is the format used by the GBA and NDS.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bgr555;
+impl PixelFormat for Bgr555 {
+  #[inline]
+  fn pack(r: u8, g: u8, b: u8) -> u16 {
+    (u16::from(b >> 3) << 10) | (u16::from(g >> 3) << 5) | u16::from(r >> 3)
+  }
+  #[inline]
+  fn unpack(pixel: u16) -> [u8; 3] {
+    [expand5(pixel), expand5(pixel >> 5), expand5(pixel >> 10)]
+  }
+}
+
+/// Red in bits 11-15 (5 bits), green in 5-10 (6 bits), and blue in 0-4 (5
+/// bits).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Rgb565;
+impl PixelFormat for Rgb565 {
+  #[inline]
+  fn pack(r: u8, g: u8, b: u8) -> u16 {
+    (u16::from(r >> 3) << 11) | (u16::from(g >> 2) << 5) | u16::from(b >> 3)
+  }
+  #[inline]
+  fn unpack(pixel: u16) -> [u8; 3] {
+    [expand5(pixel >> 11), expand6(pixel >> 5), expand5(pixel)]
+  }
+}
+
+/// A view of a `u16` [`VolGrid2d`] as pixels of a particular [`PixelFormat`].
+///
+/// This does the packing and unpacking of color channels around each volatile
+/// access.
+///
+/// ## Generic Parameters
+/// * `F`: The pixel format, such as [`Rgb555`] or [`Rgb565`].
+/// * `R` / `W` / `WIDTH` / `HEIGHT`: As per the [`VolGrid2d`] being viewed.
+#[repr(transparent)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VolGrid2dRgb<F, R, W, const WIDTH: usize, const HEIGHT: usize> {
+  grid: VolGrid2d<u16, R, W, WIDTH, HEIGHT>,
+  format: PhantomData<F>,
+}
+
+impl<F, R, W, const WIDTH: usize, const HEIGHT: usize> Clone
+  for VolGrid2dRgb<F, R, W, WIDTH, HEIGHT>
+{
+  #[inline]
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+impl<F, R, W, const WIDTH: usize, const HEIGHT: usize> Copy
+  for VolGrid2dRgb<F, R, W, WIDTH, HEIGHT>
+{
+}
+
+impl<F, R, W, const WIDTH: usize, const HEIGHT: usize>
+  VolGrid2dRgb<F, R, W, WIDTH, HEIGHT>
+{
+  /// Views the grid using the pixel format `F`.
+  #[inline]
+  #[must_use]
+  pub const fn new(grid: VolGrid2d<u16, R, W, WIDTH, HEIGHT>) -> Self {
+    Self { grid, format: PhantomData }
+  }
+
+  /// The grid being viewed.
+  #[inline]
+  #[must_use]
+  pub const fn grid(self) -> VolGrid2d<u16, R, W, WIDTH, HEIGHT> {
+    self.grid
+  }
+}
+
+impl<F, W, const WIDTH: usize, const HEIGHT: usize>
+  VolGrid2dRgb<F, Safe, W, WIDTH, HEIGHT>
+where
+  F: PixelFormat,
+{
+  /// Volatile reads the pixel at `(x,y)` and unpacks it into `[r, g, b]`.
+  ///
+  /// ## Panics
+  /// * If either coordinate is out of bounds this will panic.
+  #[inline]
+  #[track_caller]
+  pub fn read_rgb(self, x: usize, y: usize) -> [u8; 3] {
+    F::unpack(self.grid.index(x, y).read())
+  }
+}
+impl<F, W, const WIDTH: usize, const HEIGHT: usize>
+  VolGrid2dRgb<F, Unsafe, W, WIDTH, HEIGHT>
+where
+  F: PixelFormat,
+{
+  /// Volatile reads the pixel at `(x,y)` and unpacks it into `[r, g, b]`.
+  ///
+  /// ## Panics
+  /// * If either coordinate is out of bounds this will panic.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  #[track_caller]
+  pub unsafe fn read_rgb(self, x: usize, y: usize) -> [u8; 3] {
+    F::unpack(self.grid.index(x, y).read())
+  }
+}
+
+impl<F, R, const WIDTH: usize, const HEIGHT: usize>
+  VolGrid2dRgb<F, R, Safe, WIDTH, HEIGHT>
+where
+  F: PixelFormat,
+{
+  /// Packs the color channels and volatile writes them to the pixel at `(x,y)`.
+  ///
+  /// ## Panics
+  /// * If either coordinate is out of bounds this will panic.
+  #[inline]
+  #[track_caller]
+  pub fn write_rgb(self, x: usize, y: usize, r: u8, g: u8, b: u8) {
+    self.grid.index(x, y).write(F::pack(r, g, b))
+  }
+}
+impl<F, R, const WIDTH: usize, const HEIGHT: usize>
+  VolGrid2dRgb<F, R, Unsafe, WIDTH, HEIGHT>
+where
+  F: PixelFormat,
+{
+  /// Packs the color channels and volatile writes them to the pixel at `(x,y)`.
+  ///
+  /// ## Panics
+  /// * If either coordinate is out of bounds this will panic.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  #[track_caller]
+  pub unsafe fn write_rgb(self, x: usize, y: usize, r: u8, g: u8, b: u8) {
+    self.grid.index(x, y).write(F::pack(r, g, b))
+  }
+}
+
+impl<F, R, W, const WIDTH: usize, const HEIGHT: usize> core::fmt::Debug
+  for VolGrid2dRgb<F, R, W, WIDTH, HEIGHT>
+{
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "VolGrid2dRgb<{format}, r{readability}, w{writeability}, w{width}, h{height}>(0x{address:#X})",
+      format = core::any::type_name::<F>(),
+      readability=core::any::type_name::<R>(),
+      writeability=core::any::type_name::<W>(),
+      width=WIDTH,
+      height=HEIGHT,
+      address=self.grid.as_usize())
+  }
+}
+
+#[test]
+fn test_pixel_formats() {
+  assert_eq!(Rgb555::pack(0xFF, 0, 0), 0b11111_00000_00000);
+  assert_eq!(Bgr555::pack(0xFF, 0, 0), 0b00000_00000_11111);
+  assert_eq!(Rgb565::pack(0, 0xFF, 0), 0x07E0);
+  assert_eq!(Rgb555::unpack(0x7FFF), [0xFF; 3]);
+  assert_eq!(Bgr555::unpack(0b00000_00000_10000), [0x84, 0, 0]);
+  assert_eq!(Rgb565::unpack(0xFFFF), [0xFF; 3]);
+}
+
+#[test]
+fn test_volgrid2drgb() {
+  let mut hw = [0_u16; 2 * 2];
+  let grid: VolGrid2d<u16, Safe, Safe, 2, 2> =
+    unsafe { VolGrid2d::new(hw.as_mut_ptr() as usize) };
+  let rgb: VolGrid2dRgb<Bgr555, _, _, 2, 2> = VolGrid2dRgb::new(grid);
+  rgb.write_rgb(1, 1, 0xFF, 0x00, 0xFF);
+  assert_eq!(rgb.read_rgb(1, 1), [0xFF, 0x00, 0xFF]);
+  assert_eq!(hw[3], 0b11111_00000_11111);
+}
@@ -1,4 +1,4 @@
-use crate::{VolAddress, VolGrid2d};
+use crate::{Safe, UnsafeReadWrite, VolAddress, VolGrid2d};
 
 /// Models having many "frames" of [`VolGrid2d`] within a chunk of memory.
 ///
@@ -110,6 +110,96 @@ impl<
       None
     }
   }
+
+  /// Iterates over each pair of neighboring frames, `(frame_z, frame_z+1)`.
+  ///
+  /// This is useful for comparing or copying between the buffers of a double
+  /// (or triple, etc) buffered display.
+  #[inline]
+  pub fn frame_pairs(
+    self,
+  ) -> impl Iterator<
+    Item = (
+      VolGrid2d<T, R, W, WIDTH, HEIGHT>,
+      VolGrid2d<T, R, W, WIDTH, HEIGHT>,
+    ),
+  > {
+    (1..FRAMES).map(move |z| {
+      // Both frames are in bounds, so these can't fail.
+      (self.get_frame(z - 1).unwrap(), self.get_frame(z).unwrap())
+    })
+  }
+}
+
+impl<
+    T,
+    const WIDTH: usize,
+    const HEIGHT: usize,
+    const FRAMES: usize,
+    const BYTE_STRIDE: usize,
+  > VolGrid2dStrided<T, Safe, Safe, WIDTH, HEIGHT, FRAMES, BYTE_STRIDE>
+where
+  T: Copy,
+{
+  /// Copies every element of frame `src_z` into frame `dst_z`.
+  ///
+  /// The copy goes row by row, from the start of the frame to the end. If the
+  /// two frames overlap in memory (the stride is less than the frame size)
+  /// this might not give the result you expect.
+  ///
+  /// ## Panics
+  /// * If either frame index is out of bounds.
+  #[inline]
+  #[track_caller]
+  pub fn copy_frame(self, src_z: usize, dst_z: usize) {
+    let src = self.get_frame(src_z).unwrap();
+    let dst = self.get_frame(dst_z).unwrap();
+    for y in 0..HEIGHT {
+      let src_row = src.get_row(y).unwrap();
+      let dst_row = dst.get_row(y).unwrap();
+      src_row.iter().zip(dst_row.iter()).for_each(|(s, d)| d.write(s.read()));
+    }
+  }
+}
+impl<
+    T,
+    R,
+    W,
+    const WIDTH: usize,
+    const HEIGHT: usize,
+    const FRAMES: usize,
+    const BYTE_STRIDE: usize,
+  > VolGrid2dStrided<T, R, W, WIDTH, HEIGHT, FRAMES, BYTE_STRIDE>
+where
+  T: Copy,
+  (R, W): UnsafeReadWrite,
+{
+  /// Copies every element of frame `src_z` into frame `dst_z`.
+  ///
+  /// The copy goes row by row, from the start of the frame to the end. If the
+  /// two frames overlap in memory (the stride is less than the frame size)
+  /// this might not give the result you expect.
+  ///
+  /// ## Panics
+  /// * If either frame index is out of bounds.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading/writing this address depend on the device.
+  ///   Consult your hardware manual.
+  #[inline]
+  #[track_caller]
+  pub unsafe fn copy_frame(self, src_z: usize, dst_z: usize) {
+    let src = self.get_frame(src_z).unwrap();
+    let dst = self.get_frame(dst_z).unwrap();
+    for y in 0..HEIGHT {
+      let src_row = src.get_row(y).unwrap();
+      let dst_row = dst.get_row(y).unwrap();
+      src_row
+        .iter()
+        .zip(dst_row.iter())
+        .for_each(|(s, d)| d.write_raw(s.read_raw()));
+    }
+  }
 }
 
 #[test]
@@ -124,3 +214,18 @@ fn test_vol_grid_2d_strided() {
   assert_eq!(small.get_frame(5).unwrap().as_usize(), 0x1500);
   assert!(small.get_frame(6).is_none());
 }
+
+#[test]
+fn test_vol_grid_2d_strided_frames() {
+  let mut hw = [0_u8; 3 * 4];
+  let frames: VolGrid2dStrided<u8, Safe, Safe, 2, 2, 3, 4> =
+    unsafe { VolGrid2dStrided::new(hw.as_mut_ptr() as usize) };
+  frames.get_frame(0).unwrap().index(1, 1).write(7);
+  frames.copy_frame(0, 2);
+  assert_eq!(hw, [0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 7]);
+  let mut pairs = frames.frame_pairs();
+  let (a, b) = pairs.next().unwrap();
+  assert_eq!(b.as_usize() - a.as_usize(), 4);
+  assert!(pairs.next().is_some());
+  assert!(pairs.next().is_none());
+}
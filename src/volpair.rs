@@ -0,0 +1,220 @@
+use super::*;
+
+/// A 32-bit value that's split across two 16-bit registers.
+///
+/// Many chips expose a 32-bit value as a "low" and a "high" register, where
+/// the order of access matters. For example, writing the high half might
+/// latch the whole pair, so the low half must be written first. This type
+/// always accesses the two halves in the same order, so each access site
+/// can't get it wrong.
+///
+/// ## Generic Parameters
+/// * `R` / `W`: As with [`VolAddress`].
+/// * `RO` / `WO`: The [`WordOrder`] for reads and writes. Both default to
+///   [`LowFirst`].
+pub struct VolPairAddress<R, W, RO = LowFirst, WO = LowFirst> {
+  lo: VolAddress<u16, R, W>,
+  hi: VolAddress<u16, R, W>,
+  order: PhantomData<(RO, WO)>,
+}
+
+impl<R, W, RO, WO> Clone for VolPairAddress<R, W, RO, WO> {
+  #[inline]
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+impl<R, W, RO, WO> Copy for VolPairAddress<R, W, RO, WO> {}
+
+impl<R, W, RO, WO> VolPairAddress<R, W, RO, WO> {
+  /// Makes a pair from the low and high registers.
+  ///
+  /// The registers don't need to be next to each other.
+  #[inline]
+  #[must_use]
+  pub const fn new(
+    lo: VolAddress<u16, R, W>, hi: VolAddress<u16, R, W>,
+  ) -> Self {
+    Self { lo, hi, order: PhantomData }
+  }
+
+  /// The low register.
+  #[inline]
+  #[must_use]
+  pub const fn lo(self) -> VolAddress<u16, R, W> {
+    self.lo
+  }
+
+  /// The high register.
+  #[inline]
+  #[must_use]
+  pub const fn hi(self) -> VolAddress<u16, R, W> {
+    self.hi
+  }
+}
+
+impl<R, W, RO, WO> VolPairAddress<R, W, RO, WO> {
+  /// The retry loop used by every `read_counter_with` method, whatever the
+  /// permission.
+  ///
+  /// ## Safety
+  /// * The caller must have the permission to read the registers.
+  #[inline]
+  unsafe fn read_counter_raw<S: WaitStrategy>(self, mut wait: S) -> u32 {
+    let mut hi = self.hi.read_raw();
+    loop {
+      let lo = self.lo.read_raw();
+      let hi_again = self.hi.read_raw();
+      if hi == hi_again {
+        return u32::from(lo) | (u32::from(hi) << 16);
+      }
+      hi = hi_again;
+      wait.wait();
+    }
+  }
+}
+
+impl<W, RO: WordOrder, WO> VolPairAddress<Safe, W, RO, WO> {
+  /// Volatile reads both halves, in the order given by `RO`.
+  #[inline]
+  pub fn read(self) -> u32 {
+    let (lo, hi) = if RO::HIGH_FIRST {
+      let hi = self.hi.read();
+      (self.lo.read(), hi)
+    } else {
+      let lo = self.lo.read();
+      (lo, self.hi.read())
+    };
+    u32::from(lo) | (u32::from(hi) << 16)
+  }
+
+  /// Reads a running counter, retrying until the halves agree.
+  ///
+  /// The high half is read, then the low half, then the high half again. If
+  /// the high half changed then the low half rolled over between the reads,
+  /// so the whole thing is tried again. This gives a coherent value for
+  /// counters that don't latch the full value on either access. The read
+  /// order `RO` isn't used.
+  ///
+  /// This is the same as [`read_counter_with`](Self::read_counter_with) using
+  /// [`SpinHint`].
+  #[inline]
+  pub fn read_counter(self) -> u32 {
+    self.read_counter_with(SpinHint)
+  }
+
+  /// Same as [`read_counter`](Self::read_counter), but waits with the
+  /// strategy given before each retry.
+  #[inline]
+  pub fn read_counter_with<S: WaitStrategy>(self, wait: S) -> u32 {
+    // Safety: The declarer of the value gave this a `Safe` read typing, thus
+    // they've asserted that this is a safe to read address.
+    unsafe { self.read_counter_raw(wait) }
+  }
+}
+impl<W, RO: WordOrder, WO> VolPairAddress<Unsafe, W, RO, WO> {
+  /// Volatile reads both halves, in the order given by `RO`.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn read(self) -> u32 {
+    let (lo, hi) = if RO::HIGH_FIRST {
+      let hi = self.hi.read();
+      (self.lo.read(), hi)
+    } else {
+      let lo = self.lo.read();
+      (lo, self.hi.read())
+    };
+    u32::from(lo) | (u32::from(hi) << 16)
+  }
+
+  /// Reads a running counter, retrying until the halves agree.
+  ///
+  /// The high half is read, then the low half, then the high half again. If
+  /// the high half changed then the low half rolled over between the reads,
+  /// so the whole thing is tried again. This gives a coherent value for
+  /// counters that don't latch the full value on either access. The read
+  /// order `RO` isn't used.
+  ///
+  /// This is the same as [`read_counter_with`](Self::read_counter_with) using
+  /// [`SpinHint`].
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn read_counter(self) -> u32 {
+    self.read_counter_with(SpinHint)
+  }
+
+  /// Same as [`read_counter`](Self::read_counter), but waits with the
+  /// strategy given before each retry.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn read_counter_with<S: WaitStrategy>(self, wait: S) -> u32 {
+    self.read_counter_raw(wait)
+  }
+}
+
+impl<R, RO, WO: WordOrder> VolPairAddress<R, Safe, RO, WO> {
+  /// Volatile writes both halves, in the order given by `WO`.
+  #[inline]
+  pub fn write(self, u: u32) {
+    if WO::HIGH_FIRST {
+      self.hi.write((u >> 16) as u16);
+      self.lo.write(u as u16);
+    } else {
+      self.lo.write(u as u16);
+      self.hi.write((u >> 16) as u16);
+    }
+  }
+}
+impl<R, RO, WO: WordOrder> VolPairAddress<R, Unsafe, RO, WO> {
+  /// Volatile writes both halves, in the order given by `WO`.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn write(self, u: u32) {
+    if WO::HIGH_FIRST {
+      self.hi.write((u >> 16) as u16);
+      self.lo.write(u as u16);
+    } else {
+      self.lo.write(u as u16);
+      self.hi.write((u >> 16) as u16);
+    }
+  }
+}
+
+impl<R, W, RO, WO> core::fmt::Debug for VolPairAddress<R, W, RO, WO> {
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "VolPairAddress<r{readability}, w{writeability}, {read_order}, {write_order}>(lo: 0x{lo:#X}, hi: 0x{hi:#X})",
+      readability=core::any::type_name::<R>(),
+      writeability=core::any::type_name::<W>(),
+      read_order=core::any::type_name::<RO>(),
+      write_order=core::any::type_name::<WO>(),
+      lo=self.lo.as_usize(),
+      hi=self.hi.as_usize())
+  }
+}
+
+#[test]
+fn test_volpairaddress() {
+  let mut hw = [0_u16; 3];
+  let lo: VolAddress<u16, Safe, Safe> =
+    unsafe { VolAddress::new(hw.as_mut_ptr() as usize) };
+  let hi = unsafe { lo.add(2) };
+  let pair: VolPairAddress<_, _, LowFirst, HighFirst> =
+    VolPairAddress::new(lo, hi);
+  pair.write(0x1234_5678);
+  assert_eq!(hw, [0x5678, 0, 0x1234]);
+  assert_eq!(pair.read(), 0x1234_5678);
+  assert_eq!(pair.read_counter(), 0x1234_5678);
+}
@@ -12,8 +12,7 @@ use super::*;
 ///
 /// A `VolRegion` assumes that elements of the region are directly one after the
 /// other (again, like how `VolBlock` works). If you need dynamic bounds
-/// checking on a spaced out series of values that would be some other type,
-/// which doesn't currently exist in the library. (Open a PR maybe?)
+/// checking on a spaced out series of values, use [`VolStridedRegion`].
 ///
 /// ## Generic Parameters
 /// * `T` / `R` / `W`: These parameters are applied to the [`VolAddress`] type
@@ -74,14 +73,104 @@ impl<T, R, W> VolRegion<T, R, W> {
     Self { addr, len }
   }
 
+  /// Constructs a region from a raw address and length, or gives `None` if the
+  /// address is zero.
+  ///
+  /// This is useful when the region is determined at runtime (eg: read from a
+  /// device tree or a PCI BAR), and so the address might be zero.
+  ///
+  /// ## Safety
+  /// * As per the type docs, other than the address being non-zero.
+  #[inline]
+  #[must_use]
+  pub const unsafe fn try_new(address: usize, len: usize) -> Option<Self> {
+    match VolAddress::try_new(address) {
+      Some(addr) => Some(Self { addr, len }),
+      None => None,
+    }
+  }
+
+  /// Constructs a region spanning from one linker symbol to another.
+  ///
+  /// Linker scripts often define symbols marking the start and end of some
+  /// memory area. Declare them as `extern "C" { static START: u8; }` and pass
+  /// their addresses (with [`core::ptr::addr_of!`]). The `end` symbol should
+  /// be one byte past the end of the area.
+  ///
+  /// ```no_run
+  /// # use voladdress::*;
+  /// extern "C" {
+  ///   static __shared_start: u8;
+  ///   static __shared_end: u8;
+  /// }
+  /// let shared: VolRegion<u32, Safe, Safe> = unsafe {
+  ///   VolRegion::from_linker_symbols(
+  ///     core::ptr::addr_of!(__shared_start),
+  ///     core::ptr::addr_of!(__shared_end),
+  ///   )
+  /// };
+  /// ```
+  ///
+  /// This is [`from_start_end`](Self::from_start_end) with the pointers
+  /// checked and converted to addresses.
+  ///
+  /// ## Panics
+  /// * If `start` or `end` is null or isn't aligned for `T`.
+  /// * As per `from_start_end`.
+  ///
+  /// ## Safety
+  /// * As per the type docs.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub unsafe fn from_linker_symbols(start: *const u8, end: *const u8) -> Self {
+    Self::from_start_end(
+      VolAddress::new_checked(start as usize),
+      VolAddress::new_checked(end as usize),
+    )
+  }
+
+  /// Constructs a region from a start address and an end address.
+  ///
+  /// The end address is exclusive (one element past the end of the region),
+  /// so linker symbol pairs like `__start`/`__end` map directly onto this.
+  ///
+  /// ## Panics
+  /// * If `T` is a zero-sized type, since the length can't be found from the
+  ///   addresses.
+  /// * If `end` is before `start`.
+  /// * If the size in bytes isn't a multiple of the size of `T`.
+  ///
+  /// ## Safety
+  /// * As per the type docs.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub const unsafe fn from_start_end(
+    start: VolAddress<T, R, W>, end: VolAddress<T, R, W>,
+  ) -> Self {
+    assert!(core::mem::size_of::<T>() != 0);
+    assert!(start.as_usize() <= end.as_usize());
+    let bytes = end.as_usize() - start.as_usize();
+    let len = bytes / core::mem::size_of::<T>();
+    assert!(len * core::mem::size_of::<T>() == bytes);
+    Self { addr: start, len }
+  }
+
   /// Gets the length (in elements) of the region.
   #[inline]
   #[must_use]
-  #[allow(clippy::len_without_is_empty)]
   pub const fn len(self) -> usize {
     self.len
   }
 
+  /// If the region has no elements.
+  #[inline]
+  #[must_use]
+  pub const fn is_empty(self) -> bool {
+    self.len == 0
+  }
+
   /// Converts the `VolBlock` the `usize` for the start of the block.
   #[inline]
   #[must_use]
@@ -143,6 +232,32 @@ impl<T, R, W> VolRegion<T, R, W> {
     unsafe { self.addr.add(i) }
   }
 
+  /// Indexes to the `i`th element without a bounds check.
+  ///
+  /// This is for hot loops that have already checked their indexes, so that
+  /// they don't pay for a second check or carry a panic path.
+  ///
+  /// ## Safety
+  /// * The index must be in bounds.
+  #[inline]
+  #[must_use]
+  pub const unsafe fn index_unchecked(self, i: usize) -> VolAddress<T, R, W> {
+    self.addr.add(i)
+  }
+
+  /// Gets the address of the `i`th element without a bounds check.
+  ///
+  /// This is the same as [`index_unchecked`](Self::index_unchecked), named to
+  /// match the slice method.
+  ///
+  /// ## Safety
+  /// * The index must be in bounds.
+  #[inline]
+  #[must_use]
+  pub const unsafe fn get_unchecked(self, i: usize) -> VolAddress<T, R, W> {
+    self.index_unchecked(i)
+  }
+
   /// Gets `Some(addr)` if in bounds, or `None` if out of bounds.
   #[inline]
   #[must_use]
@@ -156,6 +271,9 @@ impl<T, R, W> VolRegion<T, R, W> {
 
   /// Gets a sub-slice of this region as a new region.
   ///
+  /// Like with slices, a range can start at the length (eg: `len..`) to give
+  /// an empty region.
+  ///
   /// ## Panics
   /// * If either specified end of the range is out of bounds this will panic.
   #[inline]
@@ -170,7 +288,7 @@ impl<T, R, W> VolRegion<T, R, W> {
       Bound::Excluded(x) => x + 1,
       Bound::Unbounded => 0,
     };
-    assert!(start_inclusive < self.len);
+    assert!(start_inclusive <= self.len);
     let end_exclusive: usize = match r.end_bound() {
       Bound::Included(i) => i + 1,
       Bound::Excluded(x) => *x,
@@ -188,6 +306,13 @@ impl<T, R, W> VolRegion<T, R, W> {
     VolBlockIter { base: self.addr, count: self.len }
   }
 
+  /// Creates an iterator over the index and address of each element.
+  #[inline]
+  #[must_use]
+  pub const fn iter_enumerated(self) -> VolEnumerate<VolBlockIter<T, R, W>> {
+    VolEnumerate { iter: self.iter(), index: 0 }
+  }
+
   /// Same as `region.sub_slice(range).iter()`
   #[inline]
   #[must_use]
@@ -197,6 +322,135 @@ impl<T, R, W> VolRegion<T, R, W> {
   ) -> VolBlockIter<T, R, W> {
     self.sub_slice(r).iter()
   }
+
+  /// Cleans the data cache for this region, using the registered
+  /// [`CacheOps`](crate::CacheOps).
+  ///
+  /// Call this after the CPU writes to memory that some other bus master (such
+  /// as DMA) will then read. The bulk write methods of this type already call
+  /// this for you.
+  #[cfg(feature = "cache_ops")]
+  #[inline]
+  pub fn clean_after_write(self) {
+    crate::cache_ops::clean(
+      self.as_usize(),
+      self.len * core::mem::size_of::<T>(),
+    )
+  }
+
+  /// Invalidates the data cache for this region, using the registered
+  /// [`CacheOps`](crate::CacheOps).
+  ///
+  /// Call this before the CPU reads memory that some other bus master (such as
+  /// DMA) has written. The bulk read methods of this type already call this for
+  /// you.
+  #[cfg(feature = "cache_ops")]
+  #[inline]
+  pub fn invalidate_before_read(self) {
+    crate::cache_ops::invalidate(
+      self.as_usize(),
+      self.len * core::mem::size_of::<T>(),
+    )
+  }
+
+  /// Makes an iterator over the region in sub-regions of `chunk_size`
+  /// elements.
+  ///
+  /// Like with slices, if the length isn't a multiple of `chunk_size` then the
+  /// last sub-region is shorter.
+  ///
+  /// ## Panics
+  /// * If `chunk_size` is 0.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub const fn chunks(self, chunk_size: usize) -> VolRegionChunks<T, R, W> {
+    assert!(chunk_size != 0);
+    VolRegionChunks { region: self, chunk_size }
+  }
+
+  /// Makes an iterator over the region in sub-regions of exactly `chunk_size`
+  /// elements.
+  ///
+  /// Like with slices, if the length isn't a multiple of `chunk_size` then the
+  /// elements left over are skipped, and can be gotten with
+  /// [`remainder`](VolRegionChunksExact::remainder).
+  ///
+  /// ## Panics
+  /// * If `chunk_size` is 0.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub const fn chunks_exact(
+    self, chunk_size: usize,
+  ) -> VolRegionChunksExact<T, R, W> {
+    assert!(chunk_size != 0);
+    let rem_len = self.len % chunk_size;
+    let main_len = self.len - rem_len;
+    VolRegionChunksExact {
+      region: VolRegion { addr: self.addr, len: main_len },
+      remainder: VolRegion {
+        addr: unsafe { self.addr.add(main_len) },
+        len: rem_len,
+      },
+      chunk_size,
+    }
+  }
+
+  /// Makes an iterator that splits the region into `n` sub-regions of nearly
+  /// equal length.
+  ///
+  /// This lets work on a region (such as a framebuffer) be split up between
+  /// several CPUs. The lengths differ by at most one, with the longer
+  /// sub-regions first. If the region has fewer than `n` elements then some
+  /// of the sub-regions are empty.
+  ///
+  /// ## Panics
+  /// * If `n` is 0.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub const fn split_evenly(self, n: usize) -> VolRegionSplitEvenly<T, R, W> {
+    assert!(n != 0);
+    VolRegionSplitEvenly { region: self, parts: n }
+  }
+
+  /// Splits the region into a read-only view and a write-only view of the same
+  /// memory.
+  ///
+  /// The two halves can then be handed out separately, so that each user only
+  /// gets the capability that it needs.
+  #[inline]
+  #[must_use]
+  pub const fn split_rw(self) -> (VolRegion<T, R, ()>, VolRegion<T, (), W>) {
+    (self.as_readonly(), self.as_writeonly())
+  }
+
+  /// Removes the write permission, giving a read-only region.
+  #[inline]
+  #[must_use]
+  pub const fn as_readonly(self) -> VolRegion<T, R, ()> {
+    VolRegion { addr: self.addr.as_readonly(), len: self.len }
+  }
+
+  /// Removes the read permission, giving a write-only region.
+  #[inline]
+  #[must_use]
+  pub const fn as_writeonly(self) -> VolRegion<T, (), W> {
+    VolRegion { addr: self.addr.as_writeonly(), len: self.len }
+  }
+}
+
+impl<T, W> VolRegion<T, Safe, W> {
+  /// Makes an iterator that volatile reads each element, from the last
+  /// element to the first.
+  ///
+  /// Each element is only read when the iterator reaches it.
+  #[inline]
+  #[must_use]
+  pub const fn iter_rev_values(self) -> VolRevValues<T, W> {
+    VolRevValues { iter: self.iter() }
+  }
 }
 
 impl<T, W> VolRegion<T, Safe, W>
@@ -210,8 +464,48 @@ where
   #[inline]
   pub fn read_to_slice(self, buffer: &mut [T]) {
     assert_eq!(self.len, buffer.len());
+    #[cfg(feature = "cache_ops")]
+    self.invalidate_before_read();
     self.iter().zip(buffer.iter_mut()).for_each(|(va, s)| *s = va.read())
   }
+
+  /// Volatile reads each element into the provided uninitialized buffer.
+  ///
+  /// Each element is read directly into its place in the buffer, one element
+  /// at a time, with [`VolAddress::read_into`]. This avoids a temporary copy
+  /// of the data on the stack. The buffer, now initialized, is returned as a
+  /// normal slice.
+  ///
+  /// ## Panics
+  /// * If the buffer's length is not *exactly* this region's length.
+  #[inline]
+  pub fn read_to_uninit_slice(self, buffer: &mut [MaybeUninit<T>]) -> &mut [T] {
+    assert_eq!(self.len, buffer.len());
+    #[cfg(feature = "cache_ops")]
+    self.invalidate_before_read();
+    self.iter().zip(buffer.iter_mut()).for_each(|(va, s)| {
+      va.read_into(s);
+    });
+    // Safety: every element of the buffer was just initialized.
+    unsafe { &mut *(buffer as *mut [MaybeUninit<T>] as *mut [T]) }
+  }
+
+  /// Volatile reads each element into the provided buffer, going from the last
+  /// element of the region to the first.
+  ///
+  /// The buffer is filled in the order that the reads happen, so `buffer[0]`
+  /// gets the region's last element.
+  ///
+  /// ## Panics
+  /// * If the buffer's length is not *exactly* this region's length.
+  #[inline]
+  #[track_caller]
+  pub fn read_to_slice_rev(self, buffer: &mut [T]) {
+    assert_eq!(self.len, buffer.len());
+    #[cfg(feature = "cache_ops")]
+    self.invalidate_before_read();
+    self.iter().rev().zip(buffer.iter_mut()).for_each(|(va, s)| *s = va.read())
+  }
 }
 impl<T, W> VolRegion<T, Unsafe, W>
 where
@@ -228,8 +522,57 @@ where
   #[inline]
   pub unsafe fn read_to_slice(self, buffer: &mut [T]) {
     assert_eq!(self.len, buffer.len());
+    #[cfg(feature = "cache_ops")]
+    self.invalidate_before_read();
     self.iter().zip(buffer.iter_mut()).for_each(|(va, s)| *s = va.read())
   }
+
+  /// Volatile reads each element into the provided uninitialized buffer.
+  ///
+  /// Each element is read directly into its place in the buffer, one element
+  /// at a time, with [`VolAddress::read_into`]. This avoids a temporary copy
+  /// of the data on the stack. The buffer, now initialized, is returned as a
+  /// normal slice.
+  ///
+  /// ## Panics
+  /// * If the buffer's length is not *exactly* this region's length.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn read_to_uninit_slice(
+    self, buffer: &mut [MaybeUninit<T>],
+  ) -> &mut [T] {
+    assert_eq!(self.len, buffer.len());
+    #[cfg(feature = "cache_ops")]
+    self.invalidate_before_read();
+    self.iter().zip(buffer.iter_mut()).for_each(|(va, s)| {
+      va.read_into(s);
+    });
+    &mut *(buffer as *mut [MaybeUninit<T>] as *mut [T])
+  }
+
+  /// Volatile reads each element into the provided buffer, going from the last
+  /// element of the region to the first.
+  ///
+  /// The buffer is filled in the order that the reads happen, so `buffer[0]`
+  /// gets the region's last element.
+  ///
+  /// ## Panics
+  /// * If the buffer's length is not *exactly* this region's length.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  #[track_caller]
+  pub unsafe fn read_to_slice_rev(self, buffer: &mut [T]) {
+    assert_eq!(self.len, buffer.len());
+    #[cfg(feature = "cache_ops")]
+    self.invalidate_before_read();
+    self.iter().rev().zip(buffer.iter_mut()).for_each(|(va, s)| *s = va.read())
+  }
 }
 
 impl<T, R> VolRegion<T, R, Safe>
@@ -243,7 +586,98 @@ where
   #[inline]
   pub fn write_from_slice(self, buffer: &[T]) {
     assert_eq!(self.len, buffer.len());
-    self.iter().zip(buffer.iter()).for_each(|(va, s)| va.write(*s))
+    self.iter().zip(buffer.iter()).for_each(|(va, s)| va.write(*s));
+    #[cfg(feature = "cache_ops")]
+    self.clean_after_write();
+  }
+
+  /// Volatile copies each element of the source region into this region.
+  ///
+  /// The regions are allowed to overlap (like `memmove`), which is useful for
+  /// things like scrolling a text console in VRAM. The copy goes forward or
+  /// backward as needed so that each element is read before it's overwritten.
+  ///
+  /// ## Panics
+  /// * If the source's length is not *exactly* this region's length.
+  #[inline]
+  #[track_caller]
+  pub fn copy_from_region<W2>(self, src: VolRegion<T, Safe, W2>) {
+    assert_eq!(self.len, src.len);
+    #[cfg(feature = "cache_ops")]
+    src.invalidate_before_read();
+    // If the destination starts inside the source, a forward copy would
+    // overwrite source elements before they're read, so go backwards.
+    if self.as_usize() > src.as_usize() {
+      self
+        .iter()
+        .rev()
+        .zip(src.iter().rev())
+        .for_each(|(d, s)| d.write(s.read()));
+    } else {
+      self.iter().zip(src.iter()).for_each(|(d, s)| d.write(s.read()));
+    }
+    #[cfg(feature = "cache_ops")]
+    self.clean_after_write();
+  }
+
+  /// Volatile writes `f(i)` to each element `i`, in order.
+  ///
+  /// This lets you generate data (such as a gradient, a lookup table, or a test
+  /// pattern) directly into the memory, without a staging buffer.
+  #[inline]
+  pub fn write_with<F: FnMut(usize) -> T>(self, mut f: F) {
+    self.iter().enumerate().for_each(|(i, va)| va.write(f(i)));
+    #[cfg(feature = "cache_ops")]
+    self.clean_after_write();
+  }
+
+  /// Volatile writes the value to every element, in order.
+  #[inline]
+  pub fn fill(self, value: T) {
+    self.iter().for_each(|va| va.write(value));
+    #[cfg(feature = "cache_ops")]
+    self.clean_after_write();
+  }
+
+  /// Volatile writes `f(i)` to each element `i`, in order.
+  ///
+  /// This is the same as [`write_with`](Self::write_with), named to go with
+  /// [`fill`](Self::fill).
+  #[inline]
+  pub fn fill_with<F: FnMut(usize) -> T>(self, f: F) {
+    self.write_with(f)
+  }
+
+  /// Volatile writes at most `max_elems` slice elements into this region,
+  /// continuing from the `resume` position.
+  ///
+  /// This lets a large copy be spread over several calls (eg: over several
+  /// vblank periods). Start with `resume` as 0, and then keep calling this
+  /// with the same `resume` variable until it returns `true`. The `resume`
+  /// value is updated to the number of elements copied so far.
+  ///
+  /// ## Panics
+  /// * If the buffer's length is not *exactly* this region's length.
+  #[inline]
+  #[track_caller]
+  pub fn copy_budgeted(
+    self, buffer: &[T], max_elems: usize, resume: &mut usize,
+  ) -> bool {
+    assert_eq!(self.len, buffer.len());
+    let start = (*resume).min(self.len);
+    let end = start.saturating_add(max_elems).min(self.len);
+    self
+      .iter()
+      .skip(start)
+      .zip(buffer[start..end].iter())
+      .for_each(|(va, s)| va.write(*s));
+    #[cfg(feature = "cache_ops")]
+    crate::cache_ops::clean(
+      self.as_usize() + start * core::mem::size_of::<T>(),
+      (end - start) * core::mem::size_of::<T>(),
+    );
+    *resume = end;
+    end == self.len
   }
 }
 impl<T, R> VolRegion<T, R, Unsafe>
@@ -261,10 +695,700 @@ where
   #[inline]
   pub unsafe fn write_from_slice(self, buffer: &[T]) {
     assert_eq!(self.len, buffer.len());
-    self.iter().zip(buffer.iter()).for_each(|(va, s)| va.write(*s))
+    self.iter().zip(buffer.iter()).for_each(|(va, s)| va.write(*s));
+    #[cfg(feature = "cache_ops")]
+    self.clean_after_write();
+  }
+
+  /// Volatile copies each element of the source region into this region.
+  ///
+  /// The regions are allowed to overlap (like `memmove`), which is useful for
+  /// things like scrolling a text console in VRAM. The copy goes forward or
+  /// backward as needed so that each element is read before it's overwritten.
+  ///
+  /// ## Panics
+  /// * If the source's length is not *exactly* this region's length.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  #[track_caller]
+  pub unsafe fn copy_from_region<W2>(self, src: VolRegion<T, Safe, W2>) {
+    assert_eq!(self.len, src.len);
+    #[cfg(feature = "cache_ops")]
+    src.invalidate_before_read();
+    // If the destination starts inside the source, a forward copy would
+    // overwrite source elements before they're read, so go backwards.
+    if self.as_usize() > src.as_usize() {
+      self
+        .iter()
+        .rev()
+        .zip(src.iter().rev())
+        .for_each(|(d, s)| d.write(s.read()));
+    } else {
+      self.iter().zip(src.iter()).for_each(|(d, s)| d.write(s.read()));
+    }
+    #[cfg(feature = "cache_ops")]
+    self.clean_after_write();
+  }
+
+  /// Volatile writes `f(i)` to each element `i`, in order.
+  ///
+  /// This lets you generate data (such as a gradient, a lookup table, or a test
+  /// pattern) directly into the memory, without a staging buffer.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn write_with<F: FnMut(usize) -> T>(self, mut f: F) {
+    self.iter().enumerate().for_each(|(i, va)| va.write(f(i)));
+    #[cfg(feature = "cache_ops")]
+    self.clean_after_write();
+  }
+
+  /// Volatile writes the value to every element, in order.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn fill(self, value: T) {
+    self.iter().for_each(|va| va.write(value));
+    #[cfg(feature = "cache_ops")]
+    self.clean_after_write();
+  }
+
+  /// Volatile writes `f(i)` to each element `i`, in order.
+  ///
+  /// This is the same as [`write_with`](Self::write_with), named to go with
+  /// [`fill`](Self::fill).
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn fill_with<F: FnMut(usize) -> T>(self, f: F) {
+    self.write_with(f)
+  }
+
+  /// Volatile writes at most `max_elems` slice elements into this region,
+  /// continuing from the `resume` position.
+  ///
+  /// This lets a large copy be spread over several calls (eg: over several
+  /// vblank periods). Start with `resume` as 0, and then keep calling this
+  /// with the same `resume` variable until it returns `true`. The `resume`
+  /// value is updated to the number of elements copied so far.
+  ///
+  /// ## Panics
+  /// * If the buffer's length is not *exactly* this region's length.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  #[track_caller]
+  pub unsafe fn copy_budgeted(
+    self, buffer: &[T], max_elems: usize, resume: &mut usize,
+  ) -> bool {
+    assert_eq!(self.len, buffer.len());
+    let start = (*resume).min(self.len);
+    let end = start.saturating_add(max_elems).min(self.len);
+    self
+      .iter()
+      .skip(start)
+      .zip(buffer[start..end].iter())
+      .for_each(|(va, s)| va.write(*s));
+    #[cfg(feature = "cache_ops")]
+    crate::cache_ops::clean(
+      self.as_usize() + start * core::mem::size_of::<T>(),
+      (end - start) * core::mem::size_of::<T>(),
+    );
+    *resume = end;
+    end == self.len
+  }
+}
+
+impl<T> VolRegion<T, Safe, Safe>
+where
+  T: Copy,
+{
+  /// Reads each element, passes it through the function, and writes back the
+  /// new value.
+  ///
+  /// Elements are updated in order, one at a time.
+  #[inline]
+  pub fn update_each<F: FnMut(T) -> T>(self, mut f: F) {
+    self.iter().for_each(|va| va.write(f(va.read())))
+  }
+}
+impl<T, R, W> VolRegion<T, R, W>
+where
+  T: Copy,
+  (R, W): UnsafeReadWrite,
+{
+  /// Reads each element, passes it through the function, and writes back the
+  /// new value.
+  ///
+  /// Elements are updated in order, one at a time.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading/writing this address depend on the device.
+  ///   Consult your hardware manual.
+  #[inline]
+  pub unsafe fn update_each<F: FnMut(T) -> T>(self, mut f: F) {
+    self.iter().for_each(|va| va.write_raw(f(va.read_raw())))
+  }
+}
+
+/// A primitive integer type, used as the access width of the
+/// [`read_to_slice_as`](VolRegion::read_to_slice_as) and
+/// [`write_from_slice_as`](VolRegion::write_from_slice_as) methods.
+///
+/// This trait is sealed, it's implemented for `u8`, `u16`, `u32`, `u64`, and
+/// `usize`.
+pub trait AccessWidth: Copy + sealed::Sealed {}
+mod sealed {
+  pub trait Sealed {}
+}
+macro_rules! impl_access_width {
+  ($($t:ty),*) => {
+    $(
+      impl sealed::Sealed for $t {}
+      impl AccessWidth for $t {}
+    )*
+  };
+}
+impl_access_width!(u8, u16, u32, u64, usize);
+
+/// Volatile reads `buffer.len()` bytes starting at `address`, using `U` sized
+/// reads for each aligned part of the span.
+unsafe fn read_bytes_as<U: AccessWidth>(address: usize, buffer: &mut [u8]) {
+  let size = core::mem::size_of::<U>();
+  let align_mask = core::mem::align_of::<U>() - 1;
+  let mut i = 0;
+  while i < buffer.len() {
+    let a = address + i;
+    if a & align_mask == 0 && buffer.len() - i >= size {
+      let u: U = read_volatile(a as *const U);
+      buffer.as_mut_ptr().add(i).cast::<U>().write_unaligned(u);
+      i += size;
+    } else {
+      buffer[i] = read_volatile(a as *const u8);
+      i += 1;
+    }
+  }
+}
+
+/// Volatile writes the buffer's bytes starting at `address`, using `U` sized
+/// writes for each aligned part of the span.
+unsafe fn write_bytes_as<U: AccessWidth>(address: usize, buffer: &[u8]) {
+  let size = core::mem::size_of::<U>();
+  let align_mask = core::mem::align_of::<U>() - 1;
+  let mut i = 0;
+  while i < buffer.len() {
+    let a = address + i;
+    if a & align_mask == 0 && buffer.len() - i >= size {
+      let u: U = buffer.as_ptr().add(i).cast::<U>().read_unaligned();
+      write_volatile(a as *mut U, u);
+      i += size;
+    } else {
+      write_volatile(a as *mut u8, buffer[i]);
+      i += 1;
+    }
+  }
+}
+
+impl<W> VolRegion<u8, Safe, W> {
+  /// Volatile reads the region into the buffer, using `U` sized accesses
+  /// where possible.
+  ///
+  /// Each part of the region that's aligned for `U` and at least as big as `U`
+  /// is read with a single `U` access, and any other bytes (at the start or
+  /// end of the region) are read one at a time. This is for busses where byte
+  /// access works but is slow.
+  ///
+  /// ## Panics
+  /// * If the buffer's length is not *exactly* this region's length.
+  #[inline]
+  pub fn read_to_slice_as<U: AccessWidth>(self, buffer: &mut [u8]) {
+    assert_eq!(self.len, buffer.len());
+    #[cfg(feature = "cache_ops")]
+    self.invalidate_before_read();
+    // Safety: this region is safe to read, and `U` is a plain integer.
+    unsafe { read_bytes_as::<U>(self.as_usize(), buffer) }
+  }
+}
+impl<W> VolRegion<u8, Unsafe, W> {
+  /// Volatile reads the region into the buffer, using `U` sized accesses
+  /// where possible.
+  ///
+  /// Each part of the region that's aligned for `U` and at least as big as `U`
+  /// is read with a single `U` access, and any other bytes (at the start or
+  /// end of the region) are read one at a time. This is for busses where byte
+  /// access works but is slow.
+  ///
+  /// ## Panics
+  /// * If the buffer's length is not *exactly* this region's length.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn read_to_slice_as<U: AccessWidth>(self, buffer: &mut [u8]) {
+    assert_eq!(self.len, buffer.len());
+    #[cfg(feature = "cache_ops")]
+    self.invalidate_before_read();
+    read_bytes_as::<U>(self.as_usize(), buffer)
   }
 }
 
+impl<R> VolRegion<u8, R, Safe> {
+  /// Volatile writes the buffer into the region, using `U` sized accesses
+  /// where possible.
+  ///
+  /// Each part of the region that's aligned for `U` and at least as big as `U`
+  /// is written with a single `U` access, and any other bytes (at the start or
+  /// end of the region) are written one at a time. This is for busses where
+  /// byte access works but is slow.
+  ///
+  /// ## Panics
+  /// * If the buffer's length is not *exactly* this region's length.
+  #[inline]
+  pub fn write_from_slice_as<U: AccessWidth>(self, buffer: &[u8]) {
+    assert_eq!(self.len, buffer.len());
+    // Safety: this region is safe to write, and `U` is a plain integer.
+    unsafe { write_bytes_as::<U>(self.as_usize(), buffer) };
+    #[cfg(feature = "cache_ops")]
+    self.clean_after_write();
+  }
+}
+impl<R> VolRegion<u8, R, Unsafe> {
+  /// Volatile writes the buffer into the region, using `U` sized accesses
+  /// where possible.
+  ///
+  /// Each part of the region that's aligned for `U` and at least as big as `U`
+  /// is written with a single `U` access, and any other bytes (at the start or
+  /// end of the region) are written one at a time. This is for busses where
+  /// byte access works but is slow.
+  ///
+  /// ## Panics
+  /// * If the buffer's length is not *exactly* this region's length.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn write_from_slice_as<U: AccessWidth>(self, buffer: &[u8]) {
+    assert_eq!(self.len, buffer.len());
+    write_bytes_as::<U>(self.as_usize(), buffer);
+    #[cfg(feature = "cache_ops")]
+    self.clean_after_write();
+  }
+}
+
+/// An iterator over a region in sub-regions.
+///
+/// You will generally not construct types of this value yourself. Instead, you
+/// obtain them via the [`VolRegion::chunks`](VolRegion::chunks) method.
+pub struct VolRegionChunks<T, R, W> {
+  region: VolRegion<T, R, W>,
+  chunk_size: usize,
+}
+
+impl<T, R, W> Clone for VolRegionChunks<T, R, W> {
+  #[inline]
+  fn clone(&self) -> Self {
+    Self { region: self.region, chunk_size: self.chunk_size }
+  }
+}
+
+impl<T, R, W> core::fmt::Debug for VolRegionChunks<T, R, W> {
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("VolRegionChunks")
+      .field("region", &self.region)
+      .field("chunk_size", &self.chunk_size)
+      .finish()
+  }
+}
+
+impl<T, R, W> core::iter::Iterator for VolRegionChunks<T, R, W> {
+  type Item = VolRegion<T, R, W>;
+
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.region.len == 0 {
+      return None;
+    }
+    let len = self.chunk_size.min(self.region.len);
+    let out = VolRegion { addr: self.region.addr, len };
+    self.region = VolRegion {
+      addr: unsafe { self.region.addr.add(len) },
+      len: self.region.len - len,
+    };
+    Some(out)
+  }
+
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let n = match self.region.len % self.chunk_size {
+      0 => self.region.len / self.chunk_size,
+      _ => self.region.len / self.chunk_size + 1,
+    };
+    (n, Some(n))
+  }
+}
+
+impl<T, R, W> core::iter::DoubleEndedIterator for VolRegionChunks<T, R, W> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    if self.region.len == 0 {
+      return None;
+    }
+    let len = match self.region.len % self.chunk_size {
+      0 => self.chunk_size,
+      rem => rem,
+    };
+    let start = self.region.len - len;
+    self.region.len = start;
+    Some(VolRegion { addr: unsafe { self.region.addr.add(start) }, len })
+  }
+}
+
+impl<T, R, W> core::iter::ExactSizeIterator for VolRegionChunks<T, R, W> {}
+
+/// An iterator over a region in sub-regions of an exact size.
+///
+/// You will generally not construct types of this value yourself. Instead, you
+/// obtain them via the [`VolRegion::chunks_exact`](VolRegion::chunks_exact)
+/// method.
+pub struct VolRegionChunksExact<T, R, W> {
+  region: VolRegion<T, R, W>,
+  remainder: VolRegion<T, R, W>,
+  chunk_size: usize,
+}
+
+impl<T, R, W> Clone for VolRegionChunksExact<T, R, W> {
+  #[inline]
+  fn clone(&self) -> Self {
+    Self {
+      region: self.region,
+      remainder: self.remainder,
+      chunk_size: self.chunk_size,
+    }
+  }
+}
+
+impl<T, R, W> core::fmt::Debug for VolRegionChunksExact<T, R, W> {
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("VolRegionChunksExact")
+      .field("region", &self.region)
+      .field("remainder", &self.remainder)
+      .field("chunk_size", &self.chunk_size)
+      .finish()
+  }
+}
+
+impl<T, R, W> VolRegionChunksExact<T, R, W> {
+  /// The elements at the end that don't fill a whole chunk.
+  #[inline]
+  #[must_use]
+  pub const fn remainder(&self) -> VolRegion<T, R, W> {
+    self.remainder
+  }
+}
+
+impl<T, R, W> core::iter::Iterator for VolRegionChunksExact<T, R, W> {
+  type Item = VolRegion<T, R, W>;
+
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.region.len == 0 {
+      return None;
+    }
+    let len = self.chunk_size;
+    let out = VolRegion { addr: self.region.addr, len };
+    self.region = VolRegion {
+      addr: unsafe { self.region.addr.add(len) },
+      len: self.region.len - len,
+    };
+    Some(out)
+  }
+
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let n = self.region.len / self.chunk_size;
+    (n, Some(n))
+  }
+}
+
+impl<T, R, W> core::iter::DoubleEndedIterator
+  for VolRegionChunksExact<T, R, W>
+{
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    if self.region.len == 0 {
+      return None;
+    }
+    let len = self.chunk_size;
+    let start = self.region.len - len;
+    self.region.len = start;
+    Some(VolRegion { addr: unsafe { self.region.addr.add(start) }, len })
+  }
+}
+
+impl<T, R, W> core::iter::ExactSizeIterator for VolRegionChunksExact<T, R, W> {}
+
+/// An iterator over a region split into nearly equal sub-regions.
+///
+/// You will generally not construct types of this value yourself. Instead, you
+/// obtain them via the [`VolRegion::split_evenly`](VolRegion::split_evenly)
+/// method.
+pub struct VolRegionSplitEvenly<T, R, W> {
+  region: VolRegion<T, R, W>,
+  parts: usize,
+}
+
+impl<T, R, W> Clone for VolRegionSplitEvenly<T, R, W> {
+  #[inline]
+  fn clone(&self) -> Self {
+    Self { region: self.region, parts: self.parts }
+  }
+}
+
+impl<T, R, W> core::fmt::Debug for VolRegionSplitEvenly<T, R, W> {
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("VolRegionSplitEvenly")
+      .field("region", &self.region)
+      .field("parts", &self.parts)
+      .finish()
+  }
+}
+
+impl<T, R, W> core::iter::Iterator for VolRegionSplitEvenly<T, R, W> {
+  type Item = VolRegion<T, R, W>;
+
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.parts == 0 {
+      return None;
+    }
+    // Rounding up here (and down in `next_back`) puts the longer parts first.
+    let len = match self.region.len % self.parts {
+      0 => self.region.len / self.parts,
+      _ => self.region.len / self.parts + 1,
+    };
+    let out = VolRegion { addr: self.region.addr, len };
+    self.region = VolRegion {
+      addr: unsafe { self.region.addr.add(len) },
+      len: self.region.len - len,
+    };
+    self.parts -= 1;
+    Some(out)
+  }
+
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (self.parts, Some(self.parts))
+  }
+}
+
+impl<T, R, W> core::iter::DoubleEndedIterator
+  for VolRegionSplitEvenly<T, R, W>
+{
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    if self.parts == 0 {
+      return None;
+    }
+    let len = self.region.len / self.parts;
+    let start = self.region.len - len;
+    self.region.len = start;
+    self.parts -= 1;
+    Some(VolRegion { addr: unsafe { self.region.addr.add(start) }, len })
+  }
+}
+
+impl<T, R, W> core::iter::ExactSizeIterator for VolRegionSplitEvenly<T, R, W> {}
+
+#[test]
+fn test_volregion_split_evenly() {
+  let region: VolRegion<u16, Safe, Safe> =
+    unsafe { VolRegion::from_raw_parts(VolAddress::new(0x1000), 7) };
+  let mut parts = region.split_evenly(3);
+  assert_eq!(parts.len(), 3);
+  let first = parts.next().unwrap();
+  assert_eq!((first.as_usize(), first.len()), (0x1000, 3));
+  let last = parts.next_back().unwrap();
+  assert_eq!((last.as_usize(), last.len()), (0x100A, 2));
+  let middle = parts.next().unwrap();
+  assert_eq!((middle.as_usize(), middle.len()), (0x1006, 2));
+  assert!(parts.next().is_none());
+  //
+  let lens: [usize; 4] = {
+    let mut lens = [0; 4];
+    region
+      .sub_slice(..2)
+      .split_evenly(4)
+      .zip(lens.iter_mut())
+      .for_each(|(r, l)| *l = r.len());
+    lens
+  };
+  assert_eq!(lens, [1, 1, 0, 0]);
+  // Clone doesn't need the element type to be Clone.
+  struct NotClone;
+  let region: VolRegion<NotClone, Safe, Safe> =
+    unsafe { VolRegion::from_raw_parts(VolAddress::new(0x1000), 4) };
+  assert_eq!(region.split_evenly(2).clone().count(), 2);
+}
+
+#[test]
+fn test_volregion_chunks() {
+  let region: VolRegion<u16, Safe, Safe> =
+    unsafe { VolRegion::from_raw_parts(VolAddress::new(0x1000), 7) };
+  let mut chunks = region.chunks(3);
+  assert_eq!(chunks.len(), 3);
+  let first = chunks.next().unwrap();
+  assert_eq!((first.as_usize(), first.len()), (0x1000, 3));
+  let last = chunks.next_back().unwrap();
+  assert_eq!((last.as_usize(), last.len()), (0x100C, 1));
+  let middle = chunks.next().unwrap();
+  assert_eq!((middle.as_usize(), middle.len()), (0x1006, 3));
+  assert!(chunks.next().is_none());
+  //
+  let mut chunks = region.chunks_exact(3);
+  assert_eq!(chunks.len(), 2);
+  assert_eq!(chunks.remainder().as_usize(), 0x100C);
+  assert_eq!(chunks.remainder().len(), 1);
+  assert_eq!(chunks.next_back().unwrap().as_usize(), 0x1006);
+  assert_eq!(chunks.next().unwrap().as_usize(), 0x1000);
+  assert!(chunks.next().is_none());
+  // Clone doesn't need the element type to be Clone.
+  struct NotClone;
+  let region: VolRegion<NotClone, Safe, Safe> =
+    unsafe { VolRegion::from_raw_parts(VolAddress::new(0x1000), 4) };
+  assert_eq!(region.chunks(2).clone().count(), 2);
+  assert_eq!(region.chunks_exact(3).clone().count(), 1);
+}
+
+#[test]
+fn test_volregion_copy_from_region() {
+  let mut hw = [1_u16, 2, 3, 4, 5];
+  let region: VolRegion<u16, Safe, Safe> = unsafe {
+    VolRegion::from_raw_parts(VolAddress::new(hw.as_mut_ptr() as usize), 5)
+  };
+  // Overlapping, with the destination after the source.
+  region.sub_slice(1..).copy_from_region(region.sub_slice(..4));
+  assert_eq!(hw, [1, 1, 2, 3, 4]);
+  // Overlapping, with the destination before the source.
+  region.sub_slice(..4).copy_from_region(region.sub_slice(1..));
+  assert_eq!(hw, [1, 2, 3, 4, 4]);
+}
+
+#[test]
+fn test_volregion_empty() {
+  let region: VolRegion<u16, Safe, Safe> =
+    unsafe { VolRegion::from_raw_parts(VolAddress::new(0x1000), 0) };
+  assert!(region.is_empty());
+  assert_eq!(region.iter().count(), 0);
+  assert!(region.sub_slice(..).is_empty());
+  assert!(region.sub_slice(0..0).is_empty());
+  assert_eq!(region.iter_range(0..).count(), 0);
+  assert!(region.get(0).is_none());
+  region.write_from_slice(&[]);
+  region.read_to_slice(&mut []);
+  assert_eq!(region.chunks(2).count(), 0);
+  //
+  let region: VolRegion<u16, Safe, Safe> =
+    unsafe { VolRegion::from_raw_parts(VolAddress::new(0x1000), 3) };
+  assert!(!region.is_empty());
+  assert!(region.sub_slice(3..).is_empty());
+}
+
+#[test]
+fn test_volregion_from_start_end() {
+  let region: VolRegion<u32, Safe, Safe> = unsafe {
+    VolRegion::from_start_end(VolAddress::new(0x1000), VolAddress::new(0x1010))
+  };
+  assert_eq!(region.as_usize(), 0x1000);
+  assert_eq!(region.len(), 4);
+  let region: VolRegion<u32, Safe, Safe> = unsafe {
+    VolRegion::from_start_end(VolAddress::new(0x1000), VolAddress::new(0x1000))
+  };
+  assert!(region.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_volregion_from_start_end_panic() {
+  let _: VolRegion<u32, Safe, Safe> = unsafe {
+    VolRegion::from_start_end(VolAddress::new(0x1010), VolAddress::new(0x1000))
+  };
+}
+
+#[test]
+#[should_panic]
+fn test_volregion_from_start_end_zst_panic() {
+  let _: VolRegion<(), Safe, Safe> = unsafe {
+    VolRegion::from_start_end(VolAddress::new(0x1000), VolAddress::new(0x1010))
+  };
+}
+
+#[test]
+fn test_volregion_split_rw() {
+  let mut hw = [0_u8; 3];
+  let region: VolRegion<u8, Safe, Safe> = unsafe {
+    VolRegion::from_raw_parts(VolAddress::new(hw.as_mut_ptr() as usize), 3)
+  };
+  let (r, w) = region.split_rw();
+  assert_eq!(r.as_usize(), w.as_usize());
+  assert_eq!(r.len(), w.len());
+  w.index(1).write(5);
+  assert_eq!(r.index(1).read(), 5);
+}
+
+#[test]
+fn test_volregion_read_to_uninit_slice() {
+  let mut hw = [1_u16, 2, 3];
+  let region: VolRegion<u16, Safe, ()> = unsafe {
+    VolRegion::from_raw_parts(VolAddress::new(hw.as_mut_ptr() as usize), 3)
+  };
+  let mut buffer = [MaybeUninit::uninit(); 3];
+  assert_eq!(region.read_to_uninit_slice(&mut buffer), &[1, 2, 3]);
+}
+
+#[test]
+fn test_volregion_try_new() {
+  let r: Option<VolRegion<u8, (), ()>> = unsafe { VolRegion::try_new(0, 10) };
+  assert!(r.is_none());
+  let r: Option<VolRegion<u8, (), ()>> = unsafe { VolRegion::try_new(4, 10) };
+  let r = r.unwrap();
+  assert_eq!(r.as_usize(), 4);
+  assert_eq!(r.len(), 10);
+}
+
+#[test]
+fn test_volregion_copy_budgeted() {
+  let mut hw = [0_u8; 5];
+  let region: VolRegion<u8, (), Safe> = unsafe {
+    VolRegion::from_raw_parts(VolAddress::new(hw.as_mut_ptr() as usize), 5)
+  };
+  let src = [1, 2, 3, 4, 5];
+  let mut resume = 0;
+  assert!(!region.copy_budgeted(&src, 2, &mut resume));
+  assert_eq!(resume, 2);
+  assert!(!region.copy_budgeted(&src, 2, &mut resume));
+  assert_eq!(resume, 4);
+  assert!(region.copy_budgeted(&src, 2, &mut resume));
+  assert_eq!(resume, 5);
+  assert!(region.copy_budgeted(&src, 2, &mut resume));
+  assert_eq!(hw, src);
+}
+
 #[test]
 fn test_volregion_sub_slice() {
   let region: VolRegion<u8, Unsafe, Unsafe> =
@@ -282,4 +1406,64 @@ fn test_volregion_sub_slice() {
 
   let sub_region = region.sub_slice(4..6);
   assert_eq!(sub_region.len, 2);
+
+  let sub_region = region.sub_slice(10..);
+  assert_eq!(sub_region.len, 0);
+}
+
+#[test]
+fn test_volregion_from_linker_symbols() {
+  let hw = [0_u32; 4];
+  let start = hw.as_ptr() as *const u8;
+  let end = unsafe { start.add(16) };
+  let region: VolRegion<u32, Safe, ()> =
+    unsafe { VolRegion::from_linker_symbols(start, end) };
+  assert_eq!(region.len(), 4);
+  assert_eq!(region.as_usize(), hw.as_ptr() as usize);
+}
+
+#[test]
+fn test_volregion_slice_as() {
+  let mut hw = [0_u32; 3];
+  let region: VolRegion<u8, Safe, Safe> = unsafe {
+    VolRegion::from_raw_parts(VolAddress::new(hw.as_mut_ptr() as usize + 1), 10)
+  };
+  let data = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+  region.write_from_slice_as::<u32>(&data);
+  let mut out = [0_u8; 10];
+  region.read_to_slice_as::<u32>(&mut out);
+  assert_eq!(out, data);
+  assert_eq!(hw[1], u32::from_ne_bytes([4, 5, 6, 7]));
+}
+
+#[test]
+fn test_volregion_write_with() {
+  let mut hw = [0_u16; 4];
+  let region: VolRegion<u16, (), Safe> = unsafe {
+    VolRegion::from_raw_parts(VolAddress::new(hw.as_mut_ptr() as usize), 4)
+  };
+  region.write_with(|i| (i * i) as u16);
+  assert_eq!(hw, [0, 1, 4, 9]);
+}
+
+#[test]
+fn test_volregion_fill() {
+  let mut hw = [1_u16, 2, 3, 4];
+  let region: VolRegion<u16, (), Safe> = unsafe {
+    VolRegion::from_raw_parts(VolAddress::new(hw.as_mut_ptr() as usize), 4)
+  };
+  region.fill(7);
+  assert_eq!(hw, [7; 4]);
+  region.sub_slice(1..3).fill(0);
+  assert_eq!(hw, [7, 0, 0, 7]);
+  region.fill_with(|i| 10 + i as u16);
+  assert_eq!(hw, [10, 11, 12, 13]);
+}
+
+#[test]
+fn test_volregion_index_unchecked() {
+  let region: VolRegion<u16, Safe, Safe> =
+    unsafe { VolRegion::from_raw_parts(VolAddress::new(0x1000), 4) };
+  assert_eq!(unsafe { region.index_unchecked(3) }, region.index(3));
+  assert_eq!(unsafe { region.get_unchecked(2) }, region.index(2));
 }
@@ -0,0 +1,176 @@
+use super::*;
+
+/// How a [`VolHwSemaphore`] register reports that the lock was acquired.
+///
+/// This is implemented by [`ZeroAcquires`] and [`NonZeroAcquires`].
+pub trait LockPolarity {
+  /// If a read of zero means that the lock was acquired.
+  const ZERO_ACQUIRES: bool;
+}
+
+/// A read of zero means that the lock was acquired.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ZeroAcquires;
+impl LockPolarity for ZeroAcquires {
+  const ZERO_ACQUIRES: bool = true;
+}
+
+/// A non-zero read means that the lock was acquired.
+///
+/// This is how the RP2040 spinlock registers work.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonZeroAcquires;
+impl LockPolarity for NonZeroAcquires {
+  const ZERO_ACQUIRES: bool = false;
+}
+
+/// A hardware semaphore (or "spinlock") register.
+///
+/// With this sort of register, reading it attempts to take the lock, and the
+/// value read says if that worked. Writing to it releases the lock. Since the
+/// hardware does the locking, this works across multiple cores, or between
+/// the CPU and a coprocessor.
+///
+/// Taking the lock gives a [`VolHwSemaphoreGuard`], which releases the lock
+/// when it's dropped.
+///
+/// ## Generic Parameters
+/// * `T`: The register type, usually an integer.
+/// * `P`: The [`LockPolarity`], either [`ZeroAcquires`] (the default) or
+///   [`NonZeroAcquires`].
+pub struct VolHwSemaphore<T, P = ZeroAcquires> {
+  reg: VolAddress<T, Safe, Safe>,
+  release: T,
+  polarity: PhantomData<P>,
+}
+
+impl<T: Copy, P> Clone for VolHwSemaphore<T, P> {
+  #[inline]
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+impl<T: Copy, P> Copy for VolHwSemaphore<T, P> {}
+
+impl<T, P> VolHwSemaphore<T, P> {
+  /// Makes a semaphore from the register and the value written to release
+  /// the lock.
+  #[inline]
+  #[must_use]
+  pub const fn new(reg: VolAddress<T, Safe, Safe>, release: T) -> Self {
+    Self { reg, release, polarity: PhantomData }
+  }
+
+  /// The semaphore register.
+  #[inline]
+  #[must_use]
+  pub const fn reg(&self) -> VolAddress<T, Safe, Safe> {
+    self.reg
+  }
+}
+
+impl<T, P> VolHwSemaphore<T, P>
+where
+  T: Copy + Default + PartialEq,
+  P: LockPolarity,
+{
+  /// Makes one attempt to take the lock.
+  ///
+  /// Gives `None` if the lock is held by someone else.
+  #[inline]
+  pub fn try_acquire(&self) -> Option<VolHwSemaphoreGuard<'_, T, P>> {
+    let is_zero = self.reg.read() == T::default();
+    if is_zero == P::ZERO_ACQUIRES {
+      Some(VolHwSemaphoreGuard { semaphore: self })
+    } else {
+      None
+    }
+  }
+
+  /// Spins until the lock is taken.
+  ///
+  /// This is the same as [`acquire_with`](Self::acquire_with) using
+  /// [`SpinHint`].
+  #[inline]
+  pub fn acquire_spin(&self) -> VolHwSemaphoreGuard<'_, T, P> {
+    self.acquire_with(SpinHint)
+  }
+
+  /// Tries to take the lock until it works, waiting with the strategy given
+  /// between each try.
+  #[inline]
+  pub fn acquire_with<S: WaitStrategy>(
+    &self, mut wait: S,
+  ) -> VolHwSemaphoreGuard<'_, T, P> {
+    loop {
+      if let Some(guard) = self.try_acquire() {
+        return guard;
+      }
+      wait.wait();
+    }
+  }
+}
+
+impl<T, P> core::fmt::Debug for VolHwSemaphore<T, P> {
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(
+      f,
+      "VolHwSemaphore<{elem_ty}, {polarity}>(0x{address:#X})",
+      elem_ty = core::any::type_name::<T>(),
+      polarity = core::any::type_name::<P>(),
+      address = self.reg.as_usize()
+    )
+  }
+}
+
+/// The lock of a [`VolHwSemaphore`], which is released when dropped.
+#[must_use = "if unused the lock is released right away"]
+pub struct VolHwSemaphoreGuard<'a, T: Copy, P> {
+  semaphore: &'a VolHwSemaphore<T, P>,
+}
+
+impl<'a, T: Copy, P> VolHwSemaphoreGuard<'a, T, P> {
+  /// Releases the lock.
+  ///
+  /// This is the same as dropping the guard, but is easier to see in the
+  /// code.
+  #[inline]
+  pub fn release(self) {
+    drop(self)
+  }
+}
+
+impl<'a, T: Copy, P> Drop for VolHwSemaphoreGuard<'a, T, P> {
+  #[inline]
+  fn drop(&mut self) {
+    self.semaphore.reg.write(self.semaphore.release)
+  }
+}
+
+impl<'a, T: Copy, P> core::fmt::Debug for VolHwSemaphoreGuard<'a, T, P> {
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_tuple("VolHwSemaphoreGuard").field(self.semaphore).finish()
+  }
+}
+
+#[test]
+fn test_volhwsemaphore() {
+  let mut hw = 0_u32;
+  let reg: VolAddress<u32, Safe, Safe> =
+    unsafe { VolAddress::new(&mut hw as *mut u32 as usize) };
+  let sem: VolHwSemaphore<u32> = VolHwSemaphore::new(reg, 0);
+  let guard = sem.try_acquire().unwrap();
+  // The "hardware" now reports that the lock is held.
+  reg.write(1);
+  assert!(sem.try_acquire().is_none());
+  guard.release();
+  assert_eq!(reg.read(), 0);
+  drop(sem.acquire_spin());
+  //
+  let sem: VolHwSemaphore<u32, NonZeroAcquires> = VolHwSemaphore::new(reg, 1);
+  assert!(sem.try_acquire().is_none());
+  reg.write(1);
+  assert!(sem.try_acquire().is_some());
+}
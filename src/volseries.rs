@@ -9,7 +9,8 @@ use super::*;
 /// * `T` / `R` / `W`: These parameters are applied to the [`VolAddress`] type
 ///   returned when accessing the series in any way (indexing, iteration, etc).
 /// * `C`: the count of elements in the series.
-/// * `S`: the stride **in bytes** between series elements.
+/// * `S`: the stride **in bytes** between series elements. This must be at
+///   least the size of `T`, which the constructors check at compile time.
 ///
 /// ## Safety
 /// * This type stores a [`VolAddress`] internally, and so you must follow all
@@ -40,14 +41,86 @@ impl<T, R, W, const C: usize, const S: usize> Copy
 impl<T, R, W, const C: usize, const S: usize> VolSeries<T, R, W, C, S> {
   /// Constructs the value.
   ///
+  /// It's checked when the program is compiled that a `T` fits within the
+  /// stride, so that elements don't overlap.
+  ///
+  /// ```compile_fail
+  /// # use voladdress::*;
+  /// const SERIES: VolSeries<u32, Safe, Safe, 8, 2> =
+  ///   unsafe { VolSeries::new(0x1000) };
+  /// ```
+  ///
   /// ## Safety
   /// * As per the type docs.
   #[inline]
   #[must_use]
   pub const unsafe fn new(base: usize) -> Self {
+    let () = StrideCheck::<T, S>::FITS;
     Self { base: VolAddress::new(base) }
   }
 
+  /// Constructs the value, checking that the base address is non-zero and
+  /// aligned.
+  ///
+  /// When used to initialize a `const` this check happens at compile time, so
+  /// a typo'd address will fail the build.
+  ///
+  /// ## Panics
+  /// * If the base address is zero or isn't aligned for `T`.
+  ///
+  /// ## Safety
+  /// * As per the type docs, other than the base address being non-zero and
+  ///   aligned.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub const unsafe fn new_checked(base: usize) -> Self {
+    let () = StrideCheck::<T, S>::FITS;
+    Self { base: VolAddress::new_checked(base) }
+  }
+
+  /// Constructs the value, with the stride also given as `E` elements of `T`.
+  ///
+  /// The byte stride `S` has to be `E * size_of::<T>()`, and this is checked
+  /// when the program is compiled. If `T` later changes size, the declaration
+  /// fails to build instead of silently having the wrong stride.
+  ///
+  /// ```
+  /// # use voladdress::*;
+  /// // Every 4th `u32`.
+  /// const SERIES: VolSeries<u32, Safe, Safe, 8, 16> =
+  ///   unsafe { VolSeries::new_element_strided::<4>(0x1000) };
+  /// ```
+  ///
+  /// ```compile_fail
+  /// # use voladdress::*;
+  /// const SERIES: VolSeries<u16, Safe, Safe, 8, 16> =
+  ///   unsafe { VolSeries::new_element_strided::<4>(0x1000) };
+  /// ```
+  ///
+  /// ## Safety
+  /// * As per the type docs.
+  #[inline]
+  #[must_use]
+  pub const unsafe fn new_element_strided<const E: usize>(base: usize) -> Self {
+    let () = ElementStrideCheck::<T, E, S>::MATCHES;
+    Self::new(base)
+  }
+
+  /// Constructs the value, or gives `None` if the base address is zero.
+  ///
+  /// ## Safety
+  /// * As per the type docs, other than the base address being non-zero.
+  #[inline]
+  #[must_use]
+  pub const unsafe fn try_new(base: usize) -> Option<Self> {
+    if base != 0 {
+      Some(Self::new(base))
+    } else {
+      None
+    }
+  }
+
   /// The length of this series (in elements).
   #[inline]
   #[must_use]
@@ -63,6 +136,50 @@ impl<T, R, W, const C: usize, const S: usize> VolSeries<T, R, W, C, S> {
     S
   }
 
+  /// Removes the write permission, giving a read-only series.
+  #[inline]
+  #[must_use]
+  pub const fn as_readonly(self) -> VolSeries<T, R, (), C, S> {
+    VolSeries { base: self.base.as_readonly() }
+  }
+
+  /// Removes the read permission, giving a write-only series.
+  #[inline]
+  #[must_use]
+  pub const fn as_writeonly(self) -> VolSeries<T, (), W, C, S> {
+    VolSeries { base: self.base.as_writeonly() }
+  }
+
+  /// View the series as an equivalent strided region.
+  ///
+  /// The length and stride become runtime values, so code can take series of
+  /// different lengths as the same type.
+  #[inline]
+  #[must_use]
+  pub const fn as_region(self) -> VolStridedRegion<T, R, W> {
+    VolStridedRegion { addr: self.base, len: C, stride: S }
+  }
+
+  /// Narrows the series to the `LEN` elements starting at index `START`.
+  ///
+  /// The stride is unchanged. This lets you give just some of the elements
+  /// (eg: a few channels of a sound chip) to code that shouldn't be able to
+  /// touch the rest.
+  ///
+  /// ## Panics
+  /// * If `START + LEN` is more than `C`. When used to initialize a `const`
+  ///   this check happens at compile time.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub const fn sub_series<const START: usize, const LEN: usize>(
+    self,
+  ) -> VolSeries<T, R, W, LEN, S> {
+    assert!(START <= C && LEN <= C - START);
+    // SAFETY: the new series is entirely within this series.
+    VolSeries { base: unsafe { self.base.cast::<[u8; S]>().add(START).cast() } }
+  }
+
   /// Indexes to the `i`th position of the memory series.
   ///
   /// ## Panics
@@ -75,6 +192,32 @@ impl<T, R, W, const C: usize, const S: usize> VolSeries<T, R, W, C, S> {
     unsafe { self.base.cast::<[u8; S]>().add(i).cast::<T>() }
   }
 
+  /// Indexes to the `i`th position without a bounds check.
+  ///
+  /// This is for hot loops that have already checked their indexes, so that
+  /// they don't pay for a second check or carry a panic path.
+  ///
+  /// ## Safety
+  /// * The index must be in bounds.
+  #[inline]
+  #[must_use]
+  pub const unsafe fn index_unchecked(self, i: usize) -> VolAddress<T, R, W> {
+    self.base.cast::<[u8; S]>().add(i).cast::<T>()
+  }
+
+  /// Gets the address of the `i`th position without a bounds check.
+  ///
+  /// This is the same as [`index_unchecked`](Self::index_unchecked), named to
+  /// match the slice method.
+  ///
+  /// ## Safety
+  /// * The index must be in bounds.
+  #[inline]
+  #[must_use]
+  pub const unsafe fn get_unchecked(self, i: usize) -> VolAddress<T, R, W> {
+    self.index_unchecked(i)
+  }
+
   /// Gets the address of the `i`th position, if it's in bounds.
   #[inline]
   #[must_use]
@@ -86,6 +229,28 @@ impl<T, R, W, const C: usize, const S: usize> VolSeries<T, R, W, C, S> {
     }
   }
 
+  /// Gets the gap bytes between element `i` and element `i + 1`.
+  ///
+  /// Some devices put other registers in the space between the elements of a
+  /// series, and this lets you get at them without hard coding more
+  /// addresses. If the stride is exactly the size of `T` the region is empty.
+  ///
+  /// ## Panics
+  /// * If `i + 1` is out of bounds.
+  ///
+  /// ## Safety
+  /// * The series only vouches for the `T` values, not the bytes between them.
+  ///   The gap bytes must be legal to access with the `R` and `W`
+  ///   permissions, which you'll need to check in your hardware manual.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub const unsafe fn gap_region(self, i: usize) -> VolRegion<u8, R, W> {
+    assert!(i + 1 < C);
+    let size = core::mem::size_of::<T>();
+    VolRegion { addr: self.base.cast::<u8>().add(i * S + size), len: S - size }
+  }
+
   /// Creates an iterator over the addresses of the memory series.
   #[inline]
   #[must_use]
@@ -93,9 +258,19 @@ impl<T, R, W, const C: usize, const S: usize> VolSeries<T, R, W, C, S> {
     VolSeriesIter { base: self.base, count: C }
   }
 
+  /// Creates an iterator over the index and address of each element.
+  #[inline]
+  #[must_use]
+  pub const fn iter_enumerated(
+    self,
+  ) -> VolEnumerate<VolSeriesIter<T, R, W, S>> {
+    VolEnumerate { iter: self.iter(), index: 0 }
+  }
+
   /// Makes an iterator over the range bounds given.
   ///
-  /// If the range given is empty then your iterator will be empty.
+  /// If the range given is empty then your iterator will be empty. Like with
+  /// slices, a range can start at the length (eg: `C..`) to be empty.
   ///
   /// ## Panics
   /// * If the start or end of the range are out of bounds for the series.
@@ -113,7 +288,7 @@ impl<T, R, W, const C: usize, const S: usize> VolSeries<T, R, W, C, S> {
       Bound::Excluded(x) => x + 1,
       Bound::Unbounded => 0,
     };
-    assert!(start_inclusive < C);
+    assert!(start_inclusive <= C);
     let end_exclusive: usize = match r.end_bound() {
       Bound::Included(i) => i + 1,
       Bound::Excluded(x) => *x,
@@ -126,10 +301,277 @@ impl<T, R, W, const C: usize, const S: usize> VolSeries<T, R, W, C, S> {
     //std::println!("start_inclusive {:?}", start_inclusive);
     //std::println!("end_exclusive {:?}", end_exclusive);
     let count = end_exclusive.saturating_sub(start_inclusive);
-    VolSeriesIter { base: self.index(start_inclusive), count }
+    VolSeriesIter {
+      base: unsafe {
+        self.base.cast::<[u8; S]>().add(start_inclusive).cast::<T>()
+      },
+      count,
+    }
+  }
+}
+
+impl<T, W, const C: usize, const S: usize> VolSeries<T, Safe, W, C, S>
+where
+  T: Copy,
+{
+  /// Volatile reads each element into the provided buffer.
+  ///
+  /// ## Panics
+  /// * If the buffer's length is not *exactly* this series's length.
+  #[inline]
+  #[track_caller]
+  pub fn read_to_slice(self, buffer: &mut [T]) {
+    assert_eq!(C, buffer.len());
+    self.iter().zip(buffer.iter_mut()).for_each(|(va, s)| *s = va.read())
+  }
+}
+impl<T, W, const C: usize, const S: usize> VolSeries<T, Unsafe, W, C, S>
+where
+  T: Copy,
+{
+  /// Volatile reads each element into the provided buffer.
+  ///
+  /// ## Panics
+  /// * If the buffer's length is not *exactly* this series's length.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  #[track_caller]
+  pub unsafe fn read_to_slice(self, buffer: &mut [T]) {
+    assert_eq!(C, buffer.len());
+    self.iter().zip(buffer.iter_mut()).for_each(|(va, s)| *s = va.read())
+  }
+}
+
+impl<T, R, const C: usize, const S: usize> VolSeries<T, R, Safe, C, S>
+where
+  T: Copy,
+{
+  /// Volatile writes each slice element into this series.
+  ///
+  /// ## Panics
+  /// * If the buffer's length is not *exactly* this series's length.
+  #[inline]
+  #[track_caller]
+  pub fn write_from_slice(self, buffer: &[T]) {
+    assert_eq!(C, buffer.len());
+    self.iter().zip(buffer.iter()).for_each(|(va, s)| va.write(*s))
+  }
+
+  /// Volatile writes `f(i)` to each element `i`, in order.
+  ///
+  /// This lets you generate data (such as a gradient, a lookup table, or a test
+  /// pattern) directly into the memory, without a staging buffer.
+  #[inline]
+  pub fn write_with<F: FnMut(usize) -> T>(self, mut f: F) {
+    self.iter().enumerate().for_each(|(i, va)| va.write(f(i)))
+  }
+
+  /// Volatile writes the default value of `T` to every element.
+  #[inline]
+  pub fn write_default(self)
+  where
+    T: Default,
+  {
+    self.iter().for_each(|va| va.write(T::default()))
+  }
+
+  /// Volatile writes the reset value of `T` to every element.
+  #[inline]
+  pub fn reset(self)
+  where
+    T: ResetValue,
+  {
+    self.iter().for_each(|va| va.write(T::RESET_VALUE))
+  }
+}
+impl<T, R, const C: usize, const S: usize> VolSeries<T, R, Unsafe, C, S>
+where
+  T: Copy,
+{
+  /// Volatile writes each slice element into this series.
+  ///
+  /// ## Panics
+  /// * If the buffer's length is not *exactly* this series's length.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  #[track_caller]
+  pub unsafe fn write_from_slice(self, buffer: &[T]) {
+    assert_eq!(C, buffer.len());
+    self.iter().zip(buffer.iter()).for_each(|(va, s)| va.write(*s))
+  }
+
+  /// Volatile writes `f(i)` to each element `i`, in order.
+  ///
+  /// This lets you generate data (such as a gradient, a lookup table, or a test
+  /// pattern) directly into the memory, without a staging buffer.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn write_with<F: FnMut(usize) -> T>(self, mut f: F) {
+    self.iter().enumerate().for_each(|(i, va)| va.write(f(i)))
+  }
+
+  /// Volatile writes the default value of `T` to every element.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn write_default(self)
+  where
+    T: Default,
+  {
+    self.iter().for_each(|va| va.write(T::default()))
+  }
+
+  /// Volatile writes the reset value of `T` to every element.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn reset(self)
+  where
+    T: ResetValue,
+  {
+    self.iter().for_each(|va| va.write(T::RESET_VALUE))
+  }
+}
+
+impl<T, const C: usize, const S: usize> VolSeries<T, Safe, Safe, C, S>
+where
+  T: Copy,
+{
+  /// Reads each element, passes it through the function, and writes back the
+  /// new value.
+  ///
+  /// Elements are updated in order, one at a time.
+  #[inline]
+  pub fn update_each<F: FnMut(T) -> T>(self, mut f: F) {
+    self.iter().for_each(|va| va.write(f(va.read())))
+  }
+}
+impl<T, R, W, const C: usize, const S: usize> VolSeries<T, R, W, C, S>
+where
+  T: Copy,
+  (R, W): UnsafeReadWrite,
+{
+  /// Reads each element, passes it through the function, and writes back the
+  /// new value.
+  ///
+  /// Elements are updated in order, one at a time.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading/writing this address depend on the device.
+  ///   Consult your hardware manual.
+  #[inline]
+  pub unsafe fn update_each<F: FnMut(T) -> T>(self, mut f: F) {
+    self.iter().for_each(|va| va.write_raw(f(va.read_raw())))
   }
 }
 
+#[test]
+fn test_volseries_sub_series() {
+  const SERIES: VolSeries<u16, Safe, Safe, 6, 0x10> =
+    unsafe { VolSeries::new(0x1000) };
+  const CHANNELS: VolSeries<u16, Safe, Safe, 2, 0x10> =
+    SERIES.sub_series::<2, 2>();
+  assert_eq!(CHANNELS.index(0).as_usize(), 0x1020);
+  assert_eq!(CHANNELS.index(1).as_usize(), 0x1030);
+  assert_eq!(SERIES.sub_series::<6, 0>().len(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_volseries_sub_series_panic() {
+  let series: VolSeries<u16, Safe, Safe, 6, 0x10> =
+    unsafe { VolSeries::new(0x1000) };
+  let _ = series.sub_series::<4, 3>();
+}
+
+#[test]
+fn test_volseries_iter_enumerated() {
+  let series: VolSeries<u16, Safe, Safe, 3, 0x10> =
+    unsafe { VolSeries::new(0x1000) };
+  let mut iter = series.iter_enumerated();
+  assert_eq!(iter.next_back(), Some((2, series.index(2))));
+  assert_eq!(iter.next(), Some((0, series.index(0))));
+  assert_eq!(iter.next(), Some((1, series.index(1))));
+  assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_volseries_read_write_slice() {
+  let mut hw = [0_u16; 6];
+  let series: VolSeries<u16, Safe, Safe, 3, 4> =
+    unsafe { VolSeries::new(hw.as_mut_ptr() as usize) };
+  series.write_from_slice(&[1, 2, 3]);
+  assert_eq!(hw, [1, 0, 2, 0, 3, 0]);
+  let mut buf = [0_u16; 3];
+  series.read_to_slice(&mut buf);
+  assert_eq!(buf, [1, 2, 3]);
+}
+
+#[test]
+#[should_panic]
+fn test_volseries_write_from_slice_panic() {
+  let mut hw = [0_u16; 6];
+  let series: VolSeries<u16, Safe, Safe, 3, 4> =
+    unsafe { VolSeries::new(hw.as_mut_ptr() as usize) };
+  series.write_from_slice(&[1, 2]);
+}
+
+#[test]
+fn test_volseries_index_block() {
+  // Four channels of three `u16` registers each, every 0x10 bytes.
+  let channels: VolSeries<[u16; 3], Safe, Safe, 4, 0x10> =
+    unsafe { VolSeries::new(0x1000) };
+  let ch2: VolBlock<u16, Safe, Safe, 3> = channels.index_block(2);
+  assert_eq!(ch2.index(1).as_usize(), 0x1022);
+  assert!(channels.get_block(4).is_none());
+}
+
+#[test]
+fn test_volseries_new_element_strided() {
+  let series: VolSeries<u16, Safe, Safe, 4, 6> =
+    unsafe { VolSeries::new_element_strided::<3>(0x1000) };
+  assert_eq!(series.index(2).as_usize(), 0x100C);
+}
+
+#[test]
+fn test_volseries_gap_region() {
+  let series: VolSeries<u32, Safe, Safe, 3, 0x10> =
+    unsafe { VolSeries::new(0x1000) };
+  let gap = unsafe { series.gap_region(1) };
+  assert_eq!(gap.as_usize(), 0x1014);
+  assert_eq!(gap.len(), 0xC);
+}
+
+#[test]
+#[should_panic]
+fn test_volseries_gap_region_panic() {
+  let series: VolSeries<u32, Safe, Safe, 3, 0x10> =
+    unsafe { VolSeries::new(0x1000) };
+  let _ = unsafe { series.gap_region(2) };
+}
+
+#[test]
+fn test_volseries_update_each() {
+  let mut hw = [1_u8, 2, 3, 4];
+  let series: VolSeries<u8, Safe, Safe, 2, 2> =
+    unsafe { VolSeries::new(hw.as_mut_ptr() as usize) };
+  series.update_each(|t| t * 10);
+  assert_eq!(hw, [10, 2, 30, 4]);
+}
+
 #[test]
 fn test_volseries_iter_range() {
   let series: VolSeries<u8, Unsafe, Unsafe, 10, 1> =
@@ -154,6 +596,9 @@ fn test_volseries_iter_range() {
   let i = series.iter_range(..=4);
   assert_eq!(i.base.as_usize(), 1);
   assert_eq!(i.count, 5);
+  //
+  let i = series.iter_range(10..);
+  assert_eq!(i.count, 0);
 }
 
 #[test]
@@ -162,7 +607,7 @@ fn test_volseries_iter_range_low_bound_panic() {
   let series: VolSeries<u8, Unsafe, Unsafe, 10, 1> =
     unsafe { VolSeries::new(1) };
   //
-  let _i = series.iter_range(10..);
+  let _i = series.iter_range(11..);
 }
 
 #[test]
@@ -174,6 +619,35 @@ fn test_volseries_iter_range_high_bound_panic() {
   let _i = series.iter_range(..=10);
 }
 
+impl<T, R, W, const N: usize, const C: usize, const S: usize>
+  VolSeries<[T; N], R, W, C, S>
+{
+  /// Gets the `i`th slot of the series as a block of `N` elements.
+  ///
+  /// This suits hardware with a few registers in a row per channel, and the
+  /// channels spaced out with a stride (eg: DMA channels or sound channels).
+  ///
+  /// ## Panics
+  /// * If the index is out of bounds this will panic.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub const fn index_block(self, i: usize) -> VolBlock<T, R, W, N> {
+    VolBlock { base: unsafe { self.index(i).cast::<T>() } }
+  }
+
+  /// Gets the `i`th slot of the series as a block of `N` elements, if it's in
+  /// bounds.
+  #[inline]
+  #[must_use]
+  pub const fn get_block(self, i: usize) -> Option<VolBlock<T, R, W, N>> {
+    match self.get(i) {
+      Some(va) => Some(VolBlock { base: unsafe { va.cast::<T>() } }),
+      None => None,
+    }
+  }
+}
+
 impl<T, R, W, const C: usize, const S: usize> core::fmt::Debug
   for VolSeries<T, R, W, C, S>
 {
@@ -198,6 +672,24 @@ impl<T, R, W, const C: usize, const S: usize> core::fmt::Pointer
   }
 }
 
+/// Compile time check that a `T` fits within a stride of `S` bytes.
+struct StrideCheck<T, const S: usize>(PhantomData<T>);
+impl<T, const S: usize> StrideCheck<T, S> {
+  const FITS: () = assert!(
+    core::mem::size_of::<T>() <= S,
+    "the size of T is more than the stride"
+  );
+}
+
+/// Compile time check for [`VolSeries::new_element_strided`].
+struct ElementStrideCheck<T, const E: usize, const S: usize>(PhantomData<T>);
+impl<T, const E: usize, const S: usize> ElementStrideCheck<T, E, S> {
+  const MATCHES: () = assert!(
+    E * core::mem::size_of::<T>() == S,
+    "the byte stride isn't the element stride times the size of T"
+  );
+}
+
 /// An iterator over a volatile series.
 ///
 /// You will generally not construct types of this value yourself. Instead, you
@@ -216,6 +708,30 @@ impl<T, R, W, const S: usize> Clone for VolSeriesIter<T, R, W, S> {
   }
 }
 
+impl<T, R, W, const S: usize> PartialEq for VolSeriesIter<T, R, W, S> {
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    self.base.as_usize() == other.base.as_usize() && self.count == other.count
+  }
+}
+impl<T, R, W, const S: usize> Eq for VolSeriesIter<T, R, W, S> {}
+
+impl<T, R, W, const S: usize> core::fmt::Debug for VolSeriesIter<T, R, W, S> {
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(
+      f,
+      "VolSeriesIter<{elem_ty}, r{readability}, w{writeability}> {{ base: {address:#X}, stride: {stride:#X}, count: {count} }}",
+      elem_ty = core::any::type_name::<T>(),
+      readability = core::any::type_name::<R>(),
+      writeability = core::any::type_name::<W>(),
+      stride = S,
+      address = self.base.as_usize(),
+      count = self.count
+    )
+  }
+}
+
 impl<T, R, W, const S: usize> core::iter::Iterator
   for VolSeriesIter<T, R, W, S>
 {
@@ -226,7 +742,11 @@ impl<T, R, W, const S: usize> core::iter::Iterator
     if n < self.count {
       let out = Some(unsafe { self.base.cast::<[u8; S]>().add(n).cast::<T>() });
       self.count -= n + 1;
-      self.base = unsafe { self.base.cast::<[u8; S]>().add(n + 1).cast::<T>() };
+      // Note: stepping past the last element could wrap the address space.
+      if self.count > 0 {
+        self.base =
+          unsafe { self.base.cast::<[u8; S]>().add(n + 1).cast::<T>() };
+      }
       out
     } else {
       self.count = 0;
@@ -285,6 +805,11 @@ impl<T, R, W, const S: usize> core::iter::DoubleEndedIterator
   }
 }
 
+impl<T, R, W, const S: usize> core::iter::ExactSizeIterator
+  for VolSeriesIter<T, R, W, S>
+{
+}
+
 #[test]
 fn test_impl_Iterator_for_VolSeriesIter() {
   let i: VolSeriesIter<u16, (), (), 0x100> = VolSeriesIter {
@@ -381,3 +906,91 @@ fn test_impl_DoubleEndedIterator_for_VolSeriesIter() {
   assert_eq!(i_c.nth_back(4), None);
   assert_eq!(i_c.nth_back(4), None);
 }
+
+#[test]
+fn test_volseries_iter_mixed_ends() {
+  let i: VolSeriesIter<u16, (), (), 0x100> = VolSeriesIter {
+    base: unsafe { VolAddress::new(core::mem::align_of::<u16>()) },
+    count: 5,
+  };
+
+  let mut i_c = i.clone().map(|a| a.as_usize());
+  assert_eq!(i_c.nth_back(1), Some(0x302));
+  assert_eq!(i_c.next(), Some(0x002));
+  assert_eq!(i_c.nth_back(0), Some(0x202));
+  assert_eq!(i_c.next(), Some(0x102));
+  assert_eq!(i_c.next_back(), None);
+  assert_eq!(i_c.next(), None);
+
+  let mut i_c = i.clone().map(|a| a.as_usize());
+  assert_eq!(i_c.nth(1), Some(0x102));
+  assert_eq!(i_c.nth_back(1), Some(0x302));
+  assert_eq!(i_c.len(), 1);
+  assert_eq!(i_c.next_back(), Some(0x202));
+  assert_eq!(i_c.next(), None);
+}
+
+#[test]
+fn test_volseriesiter_eq_debug() {
+  extern crate std;
+  use std::format;
+  // The stride is bigger than the element, so each step moves the base by
+  // the stride rather than by the size of `u16`.
+  let series: VolSeries<u16, Safe, Safe, 4, 0x10> =
+    unsafe { VolSeries::new(0x1000) };
+  let mut iter = series.iter();
+  assert_eq!(iter, series.iter_range(..));
+  iter.next();
+  assert_eq!(iter, series.iter_range(1..));
+  assert_eq!(
+    format!("{:?}", iter),
+    format!(
+      "VolSeriesIter<u16, r{safe}, w{safe}> {{ base: 0x1010, stride: 0x10, count: 3 }}",
+      safe = core::any::type_name::<Safe>()
+    )
+  );
+  // Taking from the back only changes the count.
+  iter.next_back();
+  assert_eq!(iter, series.iter_range(1..3));
+  assert_ne!(iter, series.iter_range(1..));
+  assert!(
+    format!("{:?}", iter).ends_with("{ base: 0x1010, stride: 0x10, count: 2 }")
+  );
+  // A series over the same memory with a wider stride reaches other addresses.
+  let wide: VolSeries<u16, Safe, Safe, 2, 0x20> =
+    unsafe { VolSeries::new(0x1000) };
+  let mut wide_iter = wide.iter();
+  wide_iter.next();
+  assert!(format!("{:?}", wide_iter)
+    .ends_with("{ base: 0x1020, stride: 0x20, count: 1 }"));
+}
+
+#[test]
+fn test_volseries_write_with() {
+  let mut hw = [0_u8; 6];
+  let series: VolSeries<u8, (), Safe, 3, 2> =
+    unsafe { VolSeries::new(hw.as_mut_ptr() as usize) };
+  series.write_with(|i| i as u8 + 1);
+  assert_eq!(hw, [1, 0, 2, 0, 3, 0]);
+}
+
+#[test]
+fn test_volseries_index_unchecked() {
+  let series: VolSeries<u16, Safe, Safe, 4, 0x10> =
+    unsafe { VolSeries::new(0x1000) };
+  assert_eq!(unsafe { series.index_unchecked(3) }, series.index(3));
+  assert_eq!(unsafe { series.get_unchecked(2) }, series.index(2));
+  assert_eq!(unsafe { series.index_unchecked(1) }.as_usize(), 0x1010);
+}
+
+#[test]
+fn test_volseries_iter_top_of_address_space() {
+  let series: VolSeries<u16, Safe, Safe, 4, 0x10> =
+    unsafe { VolSeries::new(usize::MAX - 0x10 * 4 + 1) };
+  let mut iter = series.iter();
+  assert_eq!(iter.next(), Some(series.index(0)));
+  assert_eq!(iter.nth(1), Some(series.index(2)));
+  assert_eq!(iter.next(), Some(series.index(3)));
+  assert_eq!(iter.next(), None);
+  assert_eq!(series.iter().last(), Some(series.index(3)));
+}
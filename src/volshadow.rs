@@ -0,0 +1,336 @@
+use super::*;
+
+/// A write-only [`VolBlock`] paired with a shadow copy of its data in RAM.
+///
+/// Some hardware tables (palettes, attribute tables, etc) can be written but
+/// not read back. This type keeps a copy of the most recently written value
+/// for each slot so that you can still do read-modify-write sequences.
+///
+/// * `read` gets the value from the shadow copy, without touching the
+///   hardware.
+/// * `write` updates the shadow copy and then writes through to the hardware.
+/// * `flush_all` writes the entire shadow copy to the hardware.
+///
+/// Because the hardware can't be read, the initial shadow values must be
+/// provided when the value is constructed. Usually this will be the hardware's
+/// documented reset values.
+///
+/// ## Generic Parameters
+/// * `T` / `W`: These parameters are applied to the write-only [`VolBlock`]
+///   that this type writes through to.
+/// * `C`: the count of elements in the block.
+#[derive(Clone)]
+pub struct VolShadowBlock<T, W, const C: usize> {
+  block: VolBlock<T, (), W, C>,
+  shadow: [T; C],
+}
+
+impl<T, W, const C: usize> VolShadowBlock<T, W, C> {
+  /// Pairs a block with the given initial shadow values.
+  ///
+  /// The shadow values should match what's actually in the hardware, but
+  /// nothing checks this. If they don't match then `read` will give
+  /// incorrect results until the mismatched slots are written.
+  #[inline]
+  #[must_use]
+  pub const fn new(block: VolBlock<T, (), W, C>, shadow: [T; C]) -> Self {
+    Self { block, shadow }
+  }
+
+  /// The block that this type writes through to.
+  #[inline]
+  #[must_use]
+  pub const fn block(&self) -> VolBlock<T, (), W, C> {
+    self.block
+  }
+
+  /// The current shadow values.
+  #[inline]
+  #[must_use]
+  pub const fn shadow(&self) -> &[T; C] {
+    &self.shadow
+  }
+
+  /// The length of this block (in elements).
+  #[inline]
+  #[must_use]
+  #[allow(clippy::len_without_is_empty)]
+  pub const fn len(&self) -> usize {
+    C
+  }
+}
+
+impl<T, W, const C: usize> VolShadowBlock<T, W, C>
+where
+  T: Copy,
+{
+  /// Reads the `i`th shadow value.
+  ///
+  /// This doesn't access the hardware at all.
+  ///
+  /// ## Panics
+  /// * If the index is out of bounds this will panic.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub fn read(&self, i: usize) -> T {
+    self.shadow[i]
+  }
+}
+
+impl<T, const C: usize> VolShadowBlock<T, Safe, C>
+where
+  T: Copy,
+{
+  /// Writes a new value to the `i`th slot of both the shadow and the hardware.
+  ///
+  /// ## Panics
+  /// * If the index is out of bounds this will panic.
+  #[inline]
+  #[track_caller]
+  pub fn write(&mut self, i: usize, t: T) {
+    self.shadow[i] = t;
+    self.block.index(i).write(t);
+  }
+
+  /// Reads the `i`th shadow value, applies the operation, and writes back the
+  /// new value.
+  ///
+  /// ## Panics
+  /// * If the index is out of bounds this will panic.
+  #[inline]
+  #[track_caller]
+  pub fn apply<F: FnOnce(&mut T)>(&mut self, i: usize, op: F) {
+    let mut temp = self.read(i);
+    op(&mut temp);
+    self.write(i, temp);
+  }
+
+  /// Writes every shadow value to the hardware.
+  #[inline]
+  pub fn flush_all(&self) {
+    self.block.iter().zip(self.shadow.iter()).for_each(|(va, s)| va.write(*s))
+  }
+}
+impl<T, const C: usize> VolShadowBlock<T, Unsafe, C>
+where
+  T: Copy,
+{
+  /// Writes a new value to the `i`th slot of both the shadow and the hardware.
+  ///
+  /// ## Panics
+  /// * If the index is out of bounds this will panic.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  #[track_caller]
+  pub unsafe fn write(&mut self, i: usize, t: T) {
+    self.shadow[i] = t;
+    self.block.index(i).write(t);
+  }
+
+  /// Reads the `i`th shadow value, applies the operation, and writes back the
+  /// new value.
+  ///
+  /// ## Panics
+  /// * If the index is out of bounds this will panic.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  #[track_caller]
+  pub unsafe fn apply<F: FnOnce(&mut T)>(&mut self, i: usize, op: F) {
+    let mut temp = self.read(i);
+    op(&mut temp);
+    self.write(i, temp);
+  }
+
+  /// Writes every shadow value to the hardware.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn flush_all(&self) {
+    self.block.iter().zip(self.shadow.iter()).for_each(|(va, s)| va.write(*s))
+  }
+}
+
+impl<T, W, const C: usize> core::fmt::Debug for VolShadowBlock<T, W, C>
+where
+  T: core::fmt::Debug,
+{
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("VolShadowBlock")
+      .field("block", &self.block)
+      .field("shadow", &self.shadow)
+      .finish()
+  }
+}
+
+/// A write-only [`VolAddress`] paired with a shadow copy of its value in RAM.
+///
+/// This is the single register version of [`VolShadowBlock`]. Write-only
+/// control registers are common on older hardware, and this lets you change
+/// just some bits of one with [`modify`](Self::modify), without having to
+/// keep track of the rest of the bits yourself.
+///
+/// Because the hardware can't be read, the initial shadow value must be
+/// provided when the value is constructed. Usually this will be the
+/// hardware's documented reset value.
+#[derive(Clone)]
+pub struct VolShadow<T, W> {
+  address: VolAddress<T, (), W>,
+  shadow: T,
+}
+
+impl<T, W> VolShadow<T, W> {
+  /// Pairs an address with the given initial shadow value.
+  ///
+  /// The shadow value should match what's actually in the hardware, but
+  /// nothing checks this.
+  #[inline]
+  #[must_use]
+  pub const fn new(address: VolAddress<T, (), W>, shadow: T) -> Self {
+    Self { address, shadow }
+  }
+
+  /// The address that this type writes through to.
+  #[inline]
+  #[must_use]
+  pub const fn address(&self) -> VolAddress<T, (), W> {
+    self.address
+  }
+}
+
+impl<T, W> VolShadow<T, W>
+where
+  T: Copy,
+{
+  /// Reads the shadow value.
+  ///
+  /// This doesn't access the hardware at all.
+  #[inline]
+  #[must_use]
+  pub fn read(&self) -> T {
+    self.shadow
+  }
+}
+
+impl<T> VolShadow<T, Safe>
+where
+  T: Copy,
+{
+  /// Writes a new value to both the shadow and the hardware.
+  #[inline]
+  pub fn write(&mut self, t: T) {
+    self.shadow = t;
+    self.address.write(t);
+  }
+
+  /// Applies the operation to the shadow value, and writes the full new value
+  /// to the hardware.
+  #[inline]
+  pub fn modify<F: FnOnce(&mut T)>(&mut self, op: F) {
+    let mut temp = self.shadow;
+    op(&mut temp);
+    self.write(temp);
+  }
+
+  /// Writes the shadow value to the hardware.
+  #[inline]
+  pub fn flush(&self) {
+    self.address.write(self.shadow)
+  }
+}
+impl<T> VolShadow<T, Unsafe>
+where
+  T: Copy,
+{
+  /// Writes a new value to both the shadow and the hardware.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn write(&mut self, t: T) {
+    self.shadow = t;
+    self.address.write(t);
+  }
+
+  /// Applies the operation to the shadow value, and writes the full new value
+  /// to the hardware.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn modify<F: FnOnce(&mut T)>(&mut self, op: F) {
+    let mut temp = self.shadow;
+    op(&mut temp);
+    self.write(temp);
+  }
+
+  /// Writes the shadow value to the hardware.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn flush(&self) {
+    self.address.write(self.shadow)
+  }
+}
+
+impl<T, W> core::fmt::Debug for VolShadow<T, W>
+where
+  T: core::fmt::Debug,
+{
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("VolShadow")
+      .field("address", &self.address)
+      .field("shadow", &self.shadow)
+      .finish()
+  }
+}
+
+#[test]
+fn test_volshadowblock() {
+  let mut hw = [0_u16; 4];
+  let p = hw.as_mut_ptr();
+  let block: VolBlock<u16, (), Safe, 4> = unsafe { VolBlock::new(p as usize) };
+  let mut shadow = VolShadowBlock::new(block, [1, 2, 3, 4]);
+  assert_eq!(shadow.read(2), 3);
+  //
+  shadow.write(1, 20);
+  assert_eq!(shadow.read(1), 20);
+  assert_eq!(unsafe { p.add(1).read_volatile() }, 20);
+  //
+  shadow.apply(1, |t| *t += 1);
+  assert_eq!(shadow.read(1), 21);
+  assert_eq!(unsafe { p.add(1).read_volatile() }, 21);
+  //
+  unsafe { p.write_bytes(0, 4) };
+  shadow.flush_all();
+  assert_eq!(hw, [1, 21, 3, 4]);
+}
+
+#[test]
+fn test_volshadow() {
+  let mut hw = 0_u16;
+  let p = &mut hw as *mut u16;
+  let a: VolAddress<u16, (), Safe> = unsafe { VolAddress::new(p as usize) };
+  let mut shadow = VolShadow::new(a, 0x0080);
+  shadow.modify(|t| *t |= 0x0001);
+  assert_eq!(shadow.read(), 0x0081);
+  assert_eq!(unsafe { p.read_volatile() }, 0x0081);
+  //
+  unsafe { p.write_volatile(0) };
+  shadow.flush();
+  assert_eq!(hw, 0x0081);
+}
@@ -0,0 +1,212 @@
+use super::*;
+
+/// Reads a group of related registers as one consistent snapshot.
+///
+/// Some hardware has a bank of registers that keep changing while you read
+/// them one at a time, such as the fields of a real time clock or the
+/// progress counters of a DMA channel. Usually the hardware also gives a
+/// sequence (or status) register that changes whenever the bank is updated.
+///
+/// A snapshot reads the sequence register, then every register of the bank,
+/// then the sequence register again. If the sequence value changed then the
+/// bank was updated partway through, so the whole thing is tried again.
+///
+/// ## Generic Parameters
+/// * `S`: The type of the sequence register.
+/// * `T`: The type of each register in the bank.
+/// * `R` / `W`: As with [`VolAddress`], for all of the registers.
+/// * `N`: The number of registers in the bank.
+pub struct VolSnapshot<S, T, R, W, const N: usize> {
+  seq: VolAddress<S, R, W>,
+  regs: [VolAddress<T, R, W>; N],
+}
+
+impl<S, T, R, W, const N: usize> Clone for VolSnapshot<S, T, R, W, N> {
+  #[inline]
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+impl<S, T, R, W, const N: usize> Copy for VolSnapshot<S, T, R, W, N> {}
+
+impl<S, T, R, W, const N: usize> VolSnapshot<S, T, R, W, N> {
+  /// Makes a snapshot helper from the sequence register and the bank.
+  ///
+  /// The registers don't need to be next to each other.
+  #[inline]
+  #[must_use]
+  pub const fn new(
+    seq: VolAddress<S, R, W>, regs: [VolAddress<T, R, W>; N],
+  ) -> Self {
+    Self { seq, regs }
+  }
+
+  /// The sequence register.
+  #[inline]
+  #[must_use]
+  pub const fn seq(self) -> VolAddress<S, R, W> {
+    self.seq
+  }
+
+  /// The registers of the bank.
+  #[inline]
+  #[must_use]
+  pub const fn regs(self) -> [VolAddress<T, R, W>; N] {
+    self.regs
+  }
+}
+
+impl<S, T, R, W, const N: usize> VolSnapshot<S, T, R, W, N>
+where
+  S: Copy + PartialEq,
+  T: Copy,
+{
+  /// The retry loop used by every `read_when_with` method, whatever the
+  /// permission.
+  ///
+  /// ## Safety
+  /// * The caller must have the permission to read the registers.
+  #[inline]
+  unsafe fn read_when_raw<F, X>(self, mut ready: F, mut wait: X) -> [T; N]
+  where
+    F: FnMut(S) -> bool,
+    X: WaitStrategy,
+  {
+    loop {
+      let before = self.seq.read_raw();
+      if ready(before) {
+        let values = self.regs.map(|a| a.read_raw());
+        if self.seq.read_raw() == before {
+          return values;
+        }
+      }
+      wait.wait();
+    }
+  }
+}
+
+impl<S, T, W, const N: usize> VolSnapshot<S, T, Safe, W, N>
+where
+  S: Copy + PartialEq,
+  T: Copy,
+{
+  /// Reads the bank until the sequence register is the same before and after.
+  #[inline]
+  pub fn read(self) -> [T; N] {
+    self.read_when(|_| true)
+  }
+
+  /// Reads the bank until the sequence register is the same before and after,
+  /// and also passes the `ready` check.
+  ///
+  /// This is for hardware with a status register that says when an update is
+  /// in progress (eg: an RTC "update in progress" bit), or a seqlock style
+  /// counter that's odd during an update.
+  ///
+  /// This is the same as [`read_when_with`](Self::read_when_with) using
+  /// [`SpinHint`].
+  #[inline]
+  pub fn read_when<F: FnMut(S) -> bool>(self, ready: F) -> [T; N] {
+    self.read_when_with(ready, SpinHint)
+  }
+
+  /// Same as [`read_when`](Self::read_when), but waits with the strategy
+  /// given before each retry.
+  #[inline]
+  pub fn read_when_with<F, X>(self, ready: F, wait: X) -> [T; N]
+  where
+    F: FnMut(S) -> bool,
+    X: WaitStrategy,
+  {
+    // Safety: The declarer of the value gave this a `Safe` read typing, thus
+    // they've asserted that this is a safe to read address.
+    unsafe { self.read_when_raw(ready, wait) }
+  }
+}
+impl<S, T, W, const N: usize> VolSnapshot<S, T, Unsafe, W, N>
+where
+  S: Copy + PartialEq,
+  T: Copy,
+{
+  /// Reads the bank until the sequence register is the same before and after.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn read(self) -> [T; N] {
+    self.read_when(|_| true)
+  }
+
+  /// Reads the bank until the sequence register is the same before and after,
+  /// and also passes the `ready` check.
+  ///
+  /// This is for hardware with a status register that says when an update is
+  /// in progress (eg: an RTC "update in progress" bit), or a seqlock style
+  /// counter that's odd during an update.
+  ///
+  /// This is the same as [`read_when_with`](Self::read_when_with) using
+  /// [`SpinHint`].
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn read_when<F: FnMut(S) -> bool>(self, ready: F) -> [T; N] {
+    self.read_when_with(ready, SpinHint)
+  }
+
+  /// Same as [`read_when`](Self::read_when), but waits with the strategy
+  /// given before each retry.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn read_when_with<F, X>(self, ready: F, wait: X) -> [T; N]
+  where
+    F: FnMut(S) -> bool,
+    X: WaitStrategy,
+  {
+    self.read_when_raw(ready, wait)
+  }
+}
+
+impl<S, T, R, W, const N: usize> core::fmt::Debug
+  for VolSnapshot<S, T, R, W, N>
+{
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("VolSnapshot")
+      .field("seq", &self.seq)
+      .field("regs", &self.regs)
+      .finish()
+  }
+}
+
+#[test]
+fn test_volsnapshot() {
+  let mut hw = [1_u8, 10, 20, 30];
+  let seq: VolAddress<u8, Safe, Safe> =
+    unsafe { VolAddress::new(hw.as_mut_ptr() as usize) };
+  let regs = unsafe { [seq.add(1), seq.add(2), seq.add(3)] };
+  let snap = VolSnapshot::new(seq, regs);
+  assert_eq!(snap.read(), [10, 20, 30]);
+  // The sequence is odd while an update is in progress, so only an even value
+  // is accepted. Here the "hardware" finishes the update once it's been seen.
+  assert_eq!(
+    snap.read_when(|s| {
+      if s & 1 != 0 {
+        seq.write(s + 1);
+      }
+      s & 1 == 0
+    }),
+    [10, 20, 30]
+  );
+  assert_eq!(hw[0], 2);
+  // This time the "hardware" finishes the update while we wait.
+  seq.write(3);
+  let finish_update = || seq.write(seq.read() + 1);
+  assert_eq!(snap.read_when_with(|s| s & 1 == 0, finish_update), [10, 20, 30]);
+  assert_eq!(hw[0], 4);
+}
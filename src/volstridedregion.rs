@@ -0,0 +1,207 @@
+use super::*;
+
+/// A dynamically sized span of evenly spaced volatile values.
+///
+/// If you think of [`VolSeries`] as being similar to an array with a stride,
+/// this type is the matching slice. It's to `VolSeries` what [`VolRegion`] is
+/// to [`VolBlock`].
+///
+/// Since the length is a runtime value, code that takes "some strided chunk of
+/// registers" can take this type and accept series of any length.
+///
+/// ## Generic Parameters
+/// * `T` / `R` / `W`: These parameters are applied to the [`VolAddress`] type
+///   returned when accessing the region in any way (indexing, iteration, etc).
+///
+/// ## Safety
+/// * This type stores a base [`VolAddress`] internally, and so you must follow
+///   all of those safety rules. Notably, the base address must never be zero.
+/// * The address space must legally contain `len` values of the `T` type,
+///   spaced every `stride` bytes, starting from the base address.
+/// * The region must not wrap around past the end of the address space.
+#[repr(C)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VolStridedRegion<T, R, W> {
+  pub(crate) addr: VolAddress<T, R, W>,
+  pub(crate) len: usize,
+  pub(crate) stride: usize,
+}
+impl<T, R, W> Clone for VolStridedRegion<T, R, W> {
+  #[inline]
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+impl<T, R, W> Copy for VolStridedRegion<T, R, W> {}
+impl<T, R, W> core::fmt::Debug for VolStridedRegion<T, R, W> {
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "VolStridedRegion<{elem_ty}, r{readability}, w{writeability}>({address:#X}, len: {len}, stride: {stride})",
+      elem_ty = core::any::type_name::<T>(),
+      readability=core::any::type_name::<R>(),
+      writeability=core::any::type_name::<W>(),
+      address=self.addr.as_usize(),
+      len=self.len,
+      stride=self.stride,
+    )
+  }
+}
+impl<T, R, W, const C: usize, const S: usize> From<VolSeries<T, R, W, C, S>>
+  for VolStridedRegion<T, R, W>
+{
+  #[inline]
+  fn from(series: VolSeries<T, R, W, C, S>) -> Self {
+    series.as_region()
+  }
+}
+
+impl<T, R, W> VolStridedRegion<T, R, W> {
+  /// Constructs a strided region from raw parts.
+  ///
+  /// The `stride` is in bytes.
+  ///
+  /// ## Safety
+  /// * As per the type docs.
+  #[inline]
+  #[must_use]
+  pub const unsafe fn from_raw_parts(
+    addr: VolAddress<T, R, W>, len: usize, stride: usize,
+  ) -> Self {
+    Self { addr, len, stride }
+  }
+
+  /// Gets the length (in elements) of the region.
+  #[inline]
+  #[must_use]
+  pub const fn len(self) -> usize {
+    self.len
+  }
+
+  /// If the region has no elements.
+  #[inline]
+  #[must_use]
+  pub const fn is_empty(self) -> bool {
+    self.len == 0
+  }
+
+  /// Gets the stride (in bytes) between elements of the region.
+  #[inline]
+  #[must_use]
+  pub const fn stride(self) -> usize {
+    self.stride
+  }
+
+  /// Converts the base address of the region into a plain `usize` value.
+  #[inline]
+  #[must_use]
+  pub const fn as_usize(self) -> usize {
+    self.addr.as_usize()
+  }
+
+  /// Index to the `i`th element of the region.
+  ///
+  /// ## Panics
+  /// * If the index is out of bounds this will panic.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub const fn index(self, i: usize) -> VolAddress<T, R, W> {
+    assert!(i < self.len);
+    unsafe { self.addr.cast::<u8>().add(i * self.stride).cast::<T>() }
+  }
+
+  /// Gets the address of the `i`th element, if it's in bounds.
+  #[inline]
+  #[must_use]
+  pub const fn get(self, i: usize) -> Option<VolAddress<T, R, W>> {
+    if i < self.len {
+      Some(unsafe { self.addr.cast::<u8>().add(i * self.stride).cast::<T>() })
+    } else {
+      None
+    }
+  }
+
+  /// Gets a sub-slice of this region as a new region.
+  ///
+  /// The stride is unchanged. Like with slices, a range can start at the
+  /// length (eg: `len..`) to give an empty region.
+  ///
+  /// ## Panics
+  /// * If either specified end of the range is out of bounds this will panic.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub fn sub_slice<RB: core::ops::RangeBounds<usize>>(self, r: RB) -> Self {
+    use core::ops::Bound;
+    let start_inclusive: usize = match r.start_bound() {
+      Bound::Included(i) => *i,
+      Bound::Excluded(x) => x + 1,
+      Bound::Unbounded => 0,
+    };
+    assert!(start_inclusive <= self.len);
+    let end_exclusive: usize = match r.end_bound() {
+      Bound::Included(i) => i + 1,
+      Bound::Excluded(x) => *x,
+      Bound::Unbounded => self.len,
+    };
+    assert!(end_exclusive <= self.len);
+    let len = end_exclusive.saturating_sub(start_inclusive);
+    let addr = unsafe {
+      self.addr.cast::<u8>().add(start_inclusive * self.stride).cast::<T>()
+    };
+    Self { addr, len, stride: self.stride }
+  }
+
+  /// Gives an iterator over this region.
+  #[inline]
+  #[must_use]
+  pub const fn iter(self) -> VolStepIter<T, R, W> {
+    VolStepIter { base: self.addr, count: self.len, stride: self.stride }
+  }
+
+  /// Same as `region.sub_slice(range).iter()`
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub fn iter_range<RB: core::ops::RangeBounds<usize>>(
+    self, r: RB,
+  ) -> VolStepIter<T, R, W> {
+    self.sub_slice(r).iter()
+  }
+}
+
+#[test]
+fn test_volstridedregion_from_series() {
+  let series: VolSeries<u16, Safe, Safe, 3, 0x10> =
+    unsafe { VolSeries::new(0x1000) };
+  let region = series.as_region();
+  assert_eq!(region.as_usize(), 0x1000);
+  assert_eq!(region.len(), 3);
+  assert_eq!(region.stride(), 0x10);
+  assert_eq!(VolStridedRegion::from(series), region);
+}
+
+#[test]
+fn test_volstridedregion_access() {
+  let region: VolStridedRegion<u16, Safe, Safe> =
+    unsafe { VolStridedRegion::from_raw_parts(VolAddress::new(0x1000), 4, 6) };
+  assert_eq!(region.index(3).as_usize(), 0x1012);
+  assert!(region.get(4).is_none());
+  let sub = region.sub_slice(1..3);
+  assert_eq!(sub.len(), 2);
+  assert_eq!(sub.as_usize(), 0x1006);
+  assert_eq!(region.sub_slice(4..).len(), 0);
+  let mut iter = region.iter();
+  assert_eq!(iter.next(), Some(region.index(0)));
+  assert_eq!(iter.next_back(), Some(region.index(3)));
+  assert_eq!(iter.len(), 2);
+  assert_eq!(region.iter_range(2..).next(), Some(region.index(2)));
+}
+
+#[test]
+#[should_panic]
+fn test_volstridedregion_index_panic() {
+  let region: VolStridedRegion<u16, Safe, Safe> =
+    unsafe { VolStridedRegion::from_raw_parts(VolAddress::new(0x1000), 4, 6) };
+  let _ = region.index(4);
+}
@@ -0,0 +1,113 @@
+use super::*;
+
+/// Watches up to `N` readable locations for changes in value.
+///
+/// Each location is held as a `&dyn DynVolRead`, so locations of different
+/// types can share the same watch list. Every call to [`poll`](Self::poll)
+/// reads all of the locations and reports which ones have changed since the
+/// last poll. This is the core loop of things like debug overlays and
+/// hardware state monitors.
+pub struct VolWatch<'a, const N: usize> {
+  entries: [Option<(&'a dyn DynVolRead, u32)>; N],
+  len: usize,
+}
+
+impl<'a, const N: usize> VolWatch<'a, N> {
+  /// Makes an empty watch list.
+  #[inline]
+  #[must_use]
+  pub fn new() -> Self {
+    Self { entries: [None; N], len: 0 }
+  }
+
+  /// The number of locations being watched.
+  #[inline]
+  #[must_use]
+  #[allow(clippy::len_without_is_empty)]
+  pub const fn len(&self) -> usize {
+    self.len
+  }
+
+  /// Adds a location to the watch list.
+  ///
+  /// The location is read once to get its starting value. If the list is
+  /// already full then the location is not added (or read) and this gives
+  /// `false`.
+  #[inline]
+  pub fn push(&mut self, location: &'a dyn DynVolRead) -> bool {
+    match self.entries.get_mut(self.len) {
+      Some(entry) => {
+        *entry = Some((location, location.read_u32()));
+        self.len += 1;
+        true
+      }
+      None => false,
+    }
+  }
+
+  /// Gets the location at the given index of the list, along with the value it
+  /// had when last read.
+  #[inline]
+  #[must_use]
+  pub fn get(&self, i: usize) -> Option<(&'a dyn DynVolRead, u32)> {
+    self.entries.get(i).copied().flatten()
+  }
+
+  /// Reads every location and reports which ones changed.
+  ///
+  /// Element `i` of the output is `true` if the location at index `i` now has
+  /// a different value than when it was last read. The new values are stored,
+  /// and can be seen with [`get`](Self::get).
+  #[inline]
+  pub fn poll(&mut self) -> [bool; N] {
+    let mut changed = [false; N];
+    for (entry, changed) in self.entries.iter_mut().zip(changed.iter_mut()) {
+      if let Some((location, last)) = entry {
+        let new = location.read_u32();
+        *changed = new != *last;
+        *last = new;
+      }
+    }
+    changed
+  }
+}
+
+impl<'a, const N: usize> Default for VolWatch<'a, N> {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<'a, const N: usize> core::fmt::Debug for VolWatch<'a, N> {
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    let mut list = f.debug_list();
+    for (location, last) in self.entries.iter().flatten() {
+      list.entry(&format_args!(
+        "{address:#X}: {last:#X}",
+        address = location.address(),
+        last = last
+      ));
+    }
+    list.finish()
+  }
+}
+
+#[test]
+fn test_volwatch() {
+  let mut hw = [0_u16; 2];
+  let a: VolAddress<u16, Safe, Safe> =
+    unsafe { VolAddress::new(hw.as_mut_ptr() as usize) };
+  let b: VolAddress<u8, Safe, Safe> =
+    unsafe { VolAddress::new(hw.as_mut_ptr() as usize + 2) };
+  let mut watch: VolWatch<'_, 2> = VolWatch::new();
+  assert!(watch.push(&a));
+  assert!(watch.push(&b));
+  assert!(!watch.push(&a));
+  assert_eq!(watch.poll(), [false, false]);
+  b.write(3);
+  assert_eq!(watch.poll(), [false, true]);
+  assert_eq!(watch.get(1).map(|(_, last)| last), Some(3));
+  assert_eq!(watch.poll(), [false, false]);
+}
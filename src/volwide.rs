@@ -0,0 +1,334 @@
+use super::*;
+
+/// The order that the words of a [`VolWideAddress`] or [`VolPairAddress`] are
+/// accessed in.
+///
+/// This is implemented by [`LowFirst`] and [`HighFirst`].
+pub trait WordOrder {
+  /// If the high word is accessed first.
+  const HIGH_FIRST: bool;
+}
+
+/// Access the low word of a wide value first.
+///
+/// For a [`VolWideAddress`] this means starting with the lowest address.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LowFirst;
+impl WordOrder for LowFirst {
+  const HIGH_FIRST: bool = false;
+}
+
+/// Access the high word of a wide value first.
+///
+/// For a [`VolWideAddress`] this means starting with the highest address.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HighFirst;
+impl WordOrder for HighFirst {
+  const HIGH_FIRST: bool = true;
+}
+
+/// A volatile address for a value that's wider than the bus.
+///
+/// Sometimes hardware has a register that's wider than the CPU can access in a
+/// single instruction, such as a 64-bit timer on a 32-bit CPU. This type reads
+/// and writes such a value as a sequence of `U` sized volatile accesses, one
+/// for each word of the `T` value, in a fixed order. Often the hardware
+/// latches the full value when one particular word is accessed, so check your
+/// hardware manual for the order to use.
+///
+/// Note that the full access is **not** atomic. If the value can change
+/// between the word accesses (eg: a running counter), you must handle that
+/// yourself.
+///
+/// ## Generic Parameters
+/// * `T`: The wide value type. Its size must be a multiple of the size of `U`.
+/// * `U`: The word type for each individual access, such as `u32`.
+/// * `R` / `W`: As with [`VolAddress`].
+/// * `O`: The [`WordOrder`], either [`LowFirst`] (the default) or
+///   [`HighFirst`].
+///
+/// ## Safety
+/// * The address must be legal for reading and/or writing (according to `R`
+///   and `W`) each of the `U` words that make up the `T` value.
+#[repr(transparent)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VolWideAddress<T, U, R, W, O = LowFirst> {
+  address: VolAddress<U, R, W>,
+  target: PhantomData<T>,
+  order: PhantomData<O>,
+}
+
+impl<T, U, R, W, O> Clone for VolWideAddress<T, U, R, W, O> {
+  #[inline]
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+impl<T, U, R, W, O> Copy for VolWideAddress<T, U, R, W, O> {}
+
+impl<T, U, R, W, O> VolWideAddress<T, U, R, W, O> {
+  /// The number of words in each value.
+  const WORDS: usize = core::mem::size_of::<T>() / core::mem::size_of::<U>();
+
+  /// Constructs the value.
+  ///
+  /// ## Panics
+  /// * If the size of `T` isn't a multiple of the size of `U`.
+  ///
+  /// ## Safety
+  /// * As per the type docs.
+  #[inline]
+  #[must_use]
+  #[track_caller]
+  pub const unsafe fn new(address: usize) -> Self {
+    assert!(
+      Self::WORDS * core::mem::size_of::<U>() == core::mem::size_of::<T>()
+    );
+    Self {
+      address: VolAddress::new(address),
+      target: PhantomData,
+      order: PhantomData,
+    }
+  }
+
+  /// Gets the address of each word of the value, in access order.
+  #[inline]
+  fn words(self) -> impl Iterator<Item = (usize, VolAddress<U, R, W>)>
+  where
+    O: WordOrder,
+  {
+    (0..Self::WORDS).map(move |k| {
+      let i = if O::HIGH_FIRST { Self::WORDS - 1 - k } else { k };
+      // Safety: the declarer of the address asserted that each of the words
+      // of the value is part of the address space.
+      (i, unsafe { self.address.add(i) })
+    })
+  }
+
+  /// Converts the address into a normal `usize` value.
+  #[inline]
+  #[must_use]
+  pub const fn as_usize(self) -> usize {
+    self.address.as_usize()
+  }
+}
+
+impl<T, U, R, W, O> VolWideAddress<T, U, R, W, O>
+where
+  T: Copy,
+  U: Copy + PartialEq,
+{
+  /// The retry loop used by every `read_counter_with` method, whatever the
+  /// permission.
+  ///
+  /// ## Safety
+  /// * The caller must have the permission to read the address.
+  #[inline]
+  unsafe fn read_counter_raw<S: WaitStrategy>(self, mut wait: S) -> T {
+    let mut out = MaybeUninit::<T>::uninit();
+    let p = out.as_mut_ptr() as *mut U;
+    // The most significant word is at the highest address on little endian
+    // targets, and at the lowest address on big endian targets.
+    let top = if cfg!(target_endian = "big") { 0 } else { Self::WORDS - 1 };
+    let hi_va = self.address.add(top);
+    let mut hi = hi_va.read_raw();
+    loop {
+      for i in (0..Self::WORDS).filter(|&i| i != top) {
+        p.add(i).write_unaligned(self.address.add(i).read_raw());
+      }
+      let hi_again = hi_va.read_raw();
+      if hi == hi_again {
+        p.add(top).write_unaligned(hi);
+        // Note: every word of the value was just initialized, and the
+        // declarer of the address asserted that the memory holds a `T`.
+        return out.assume_init();
+      }
+      hi = hi_again;
+      wait.wait();
+    }
+  }
+}
+
+impl<T, U, W, O> VolWideAddress<T, U, Safe, W, O>
+where
+  T: Copy,
+  U: Copy,
+  O: WordOrder,
+{
+  /// Volatile reads each word of the value, in the order given by `O`.
+  #[inline]
+  pub fn read(self) -> T {
+    let mut out = MaybeUninit::<T>::uninit();
+    let p = out.as_mut_ptr() as *mut U;
+    self.words().for_each(|(i, va)| {
+      // Safety: `i` is less than the number of words in a `T`, so this is in
+      // bounds of `out`.
+      unsafe { p.add(i).write_unaligned(va.read()) };
+    });
+    // Safety: every word of the value was just initialized, and the declarer
+    // of the address asserted that the memory holds a `T`.
+    unsafe { out.assume_init() }
+  }
+
+  /// Reads a running counter, retrying until the words agree.
+  ///
+  /// The most significant word is read, then the rest of the words from low
+  /// address to high address, then the most significant word again. If the
+  /// most significant word changed then a lower word rolled over between the
+  /// reads, so the whole thing is tried again. This is the usual "hi, lo, hi"
+  /// read of a wide timer. The word order `O` isn't used.
+  ///
+  /// The most significant word is the one at the highest address on little
+  /// endian targets, and the one at the lowest address on big endian
+  /// targets.
+  ///
+  /// This is the same as [`read_counter_with`](Self::read_counter_with) using
+  /// [`SpinHint`].
+  #[inline]
+  pub fn read_counter(self) -> T
+  where
+    U: PartialEq,
+  {
+    self.read_counter_with(SpinHint)
+  }
+
+  /// Same as [`read_counter`](Self::read_counter), but waits with the
+  /// strategy given before each retry.
+  #[inline]
+  pub fn read_counter_with<S: WaitStrategy>(self, wait: S) -> T
+  where
+    U: PartialEq,
+  {
+    // Safety: The declarer of the value gave this a `Safe` read typing, thus
+    // they've asserted that this is a safe to read address.
+    unsafe { self.read_counter_raw(wait) }
+  }
+}
+impl<T, U, W, O> VolWideAddress<T, U, Unsafe, W, O>
+where
+  T: Copy,
+  U: Copy,
+  O: WordOrder,
+{
+  /// Volatile reads each word of the value, in the order given by `O`.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn read(self) -> T {
+    let mut out = MaybeUninit::<T>::uninit();
+    let p = out.as_mut_ptr() as *mut U;
+    self.words().for_each(|(i, va)| {
+      p.add(i).write_unaligned(va.read());
+    });
+    out.assume_init()
+  }
+
+  /// Reads a running counter, retrying until the words agree.
+  ///
+  /// The most significant word is read, then the rest of the words from low
+  /// address to high address, then the most significant word again. If the
+  /// most significant word changed then a lower word rolled over between the
+  /// reads, so the whole thing is tried again. This is the usual "hi, lo, hi"
+  /// read of a wide timer. The word order `O` isn't used.
+  ///
+  /// The most significant word is the one at the highest address on little
+  /// endian targets, and the one at the lowest address on big endian
+  /// targets.
+  ///
+  /// This is the same as [`read_counter_with`](Self::read_counter_with) using
+  /// [`SpinHint`].
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn read_counter(self) -> T
+  where
+    U: PartialEq,
+  {
+    self.read_counter_with(SpinHint)
+  }
+
+  /// Same as [`read_counter`](Self::read_counter), but waits with the
+  /// strategy given before each retry.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn read_counter_with<S: WaitStrategy>(self, wait: S) -> T
+  where
+    U: PartialEq,
+  {
+    self.read_counter_raw(wait)
+  }
+}
+
+impl<T, U, R, O> VolWideAddress<T, U, R, Safe, O>
+where
+  T: Copy,
+  U: Copy,
+  O: WordOrder,
+{
+  /// Volatile writes each word of the value, in the order given by `O`.
+  #[inline]
+  pub fn write(self, t: T) {
+    let p = &t as *const T as *const U;
+    self.words().for_each(|(i, va)| {
+      // Safety: `i` is less than the number of words in a `T`, so this is in
+      // bounds of `t`.
+      va.write(unsafe { p.add(i).read_unaligned() })
+    })
+  }
+}
+impl<T, U, R, O> VolWideAddress<T, U, R, Unsafe, O>
+where
+  T: Copy,
+  U: Copy,
+  O: WordOrder,
+{
+  /// Volatile writes each word of the value, in the order given by `O`.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn write(self, t: T) {
+    let p = &t as *const T as *const U;
+    self.words().for_each(|(i, va)| va.write(p.add(i).read_unaligned()))
+  }
+}
+
+impl<T, U, R, W, O> core::fmt::Debug for VolWideAddress<T, U, R, W, O> {
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "VolWideAddress<{elem_ty}, {word_ty}, r{readability}, w{writeability}, {order}>(0x{address:#X})",
+      elem_ty = core::any::type_name::<T>(),
+      word_ty = core::any::type_name::<U>(),
+      readability=core::any::type_name::<R>(),
+      writeability=core::any::type_name::<W>(),
+      order=core::any::type_name::<O>(),
+      address=self.address.as_usize())
+  }
+}
+
+#[test]
+fn test_volwideaddress() {
+  let mut hw = [0_u32; 2];
+  let a: VolWideAddress<u64, u32, Safe, Safe> =
+    unsafe { VolWideAddress::new(hw.as_mut_ptr() as usize) };
+  a.write(0x1122_3344_5566_7788);
+  assert_eq!(a.read(), 0x1122_3344_5566_7788);
+  assert_eq!(hw[0].to_ne_bytes(), 0x1122_3344_5566_7788_u64.to_ne_bytes()[..4]);
+  let b: VolWideAddress<u64, u32, Safe, Safe, HighFirst> =
+    unsafe { VolWideAddress::new(hw.as_mut_ptr() as usize) };
+  assert_eq!(b.read(), 0x1122_3344_5566_7788);
+  let order: [usize; 2] = {
+    let mut w = b.words().map(|(i, _)| i);
+    [w.next().unwrap(), w.next().unwrap()]
+  };
+  assert_eq!(order, [1, 0]);
+  assert_eq!(a.read_counter(), 0x1122_3344_5566_7788);
+}
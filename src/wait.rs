@@ -0,0 +1,148 @@
+use super::*;
+
+/// What to do while waiting for the hardware, between each poll.
+///
+/// The polling helpers of the crate (such as
+/// [`VolAddress::wait_until`] and [`VolHwSemaphore::acquire_with`]) take a
+/// strategy, so that you can pick how the CPU waits.
+///
+/// * [`SpinHint`] tells the CPU that it's in a spin loop.
+/// * [`Backoff`] spins for longer and longer each time.
+/// * Any `FnMut()` closure can be used for a custom strategy, such as
+///   sleeping until an event or interrupt, or yielding to a scheduler.
+///
+/// ```no_run
+/// # use voladdress::*;
+/// # let status: VolAddress<u16, Safe, ()> = unsafe { VolAddress::new(4) };
+/// # #[cfg(target_arch = "arm")]
+/// // Sleep until an event between each poll.
+/// status.wait_until(|s| s & 1 != 0, || unsafe { core::arch::asm!("wfe") });
+/// ```
+pub trait WaitStrategy {
+  /// Waits once, before the next poll.
+  fn wait(&mut self);
+}
+
+impl<F: FnMut()> WaitStrategy for F {
+  #[inline]
+  fn wait(&mut self) {
+    self()
+  }
+}
+
+/// Waits with [`core::hint::spin_loop`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SpinHint;
+impl WaitStrategy for SpinHint {
+  #[inline]
+  fn wait(&mut self) {
+    core::hint::spin_loop()
+  }
+}
+
+/// Waits with an exponential backoff.
+///
+/// The first wait does 1 spin, and each wait after that does twice as many
+/// spins as the one before, up to the limit given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Backoff {
+  spins: u32,
+  limit: u32,
+}
+impl Backoff {
+  /// Makes a backoff that does at most `limit` spins per wait.
+  #[inline]
+  #[must_use]
+  pub const fn new(limit: u32) -> Self {
+    Self { spins: 1, limit }
+  }
+}
+impl WaitStrategy for Backoff {
+  #[inline]
+  fn wait(&mut self) {
+    for _ in 0..self.spins {
+      core::hint::spin_loop();
+    }
+    self.spins = self.spins.saturating_mul(2).min(self.limit);
+  }
+}
+
+impl<T, R, W, A> VolAddress<T, R, W, A>
+where
+  T: Copy,
+{
+  /// The polling loop used by every `wait_until` method, whatever the
+  /// permission.
+  ///
+  /// ## Safety
+  /// * The caller must have the permission to read the address.
+  #[inline]
+  pub(crate) unsafe fn wait_until_raw<F, S>(self, mut f: F, mut wait: S) -> T
+  where
+    F: FnMut(T) -> bool,
+    S: WaitStrategy,
+  {
+    loop {
+      let t = self.read_raw();
+      if f(t) {
+        return t;
+      }
+      wait.wait();
+    }
+  }
+}
+
+impl<T, W, A> VolAddress<T, Safe, W, A>
+where
+  T: Copy,
+{
+  /// Volatile reads the address until the value passes the check, waiting
+  /// with the strategy given between each read.
+  ///
+  /// Gives the value that passed the check.
+  #[inline]
+  pub fn wait_until<F, S>(self, f: F, wait: S) -> T
+  where
+    F: FnMut(T) -> bool,
+    S: WaitStrategy,
+  {
+    // Safety: the read permission is `Safe`.
+    unsafe { self.wait_until_raw(f, wait) }
+  }
+}
+impl<T, W, A> VolAddress<T, Unsafe, W, A>
+where
+  T: Copy,
+{
+  /// Volatile reads the address until the value passes the check, waiting
+  /// with the strategy given between each read.
+  ///
+  /// Gives the value that passed the check.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn wait_until<F, S>(self, f: F, wait: S) -> T
+  where
+    F: FnMut(T) -> bool,
+    S: WaitStrategy,
+  {
+    self.wait_until_raw(f, wait)
+  }
+}
+
+#[test]
+fn test_wait_until() {
+  let mut hw = 0_u8;
+  let a: VolAddress<u8, Safe, Safe> =
+    unsafe { VolAddress::new(&mut hw as *mut u8 as usize) };
+  // The "hardware" moves along by one step each time we wait.
+  assert_eq!(a.wait_until(|t| t == 3, || a.write(a.read() + 1)), 3);
+  assert_eq!(a.wait_until(|t| t == 3, Backoff::new(16)), 3);
+  let mut backoff = Backoff::new(4);
+  backoff.wait();
+  backoff.wait();
+  backoff.wait();
+  assert_eq!(backoff, Backoff { spins: 4, limit: 4 });
+}
@@ -0,0 +1,129 @@
+use super::*;
+
+/// A [`VolAddress`] declared together with its hardware reset value.
+///
+/// The [`ResetValue`] trait gives a reset value for each *type*, which works
+/// well when every register of a type resets to the same value. When two
+/// registers share a type but not a reset value, this pairs each address with
+/// its own reset value instead, so the memory map can still say what the
+/// power-on state is.
+///
+/// ```
+/// # use voladdress::*;
+/// pub const TIMER0_RELOAD: VolAddressWithReset<u16, Safe, Safe> =
+///   VolAddressWithReset::new(unsafe { VolAddress::new(0x0400_0100) }, 0);
+/// pub const SOUNDBIAS: VolAddressWithReset<u16, Safe, Safe> =
+///   VolAddressWithReset::new(unsafe { VolAddress::new(0x0400_0088) }, 0x200);
+/// ```
+pub struct VolAddressWithReset<T, R, W> {
+  address: VolAddress<T, R, W>,
+  reset: T,
+}
+
+impl<T: Copy, R, W> Clone for VolAddressWithReset<T, R, W> {
+  #[inline]
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+impl<T: Copy, R, W> Copy for VolAddressWithReset<T, R, W> {}
+
+impl<T, R, W> VolAddressWithReset<T, R, W> {
+  /// Pairs the address with its reset value.
+  #[inline]
+  #[must_use]
+  pub const fn new(address: VolAddress<T, R, W>, reset: T) -> Self {
+    Self { address, reset }
+  }
+}
+
+impl<T: Copy, R, W> VolAddressWithReset<T, R, W> {
+  /// The address.
+  #[inline]
+  #[must_use]
+  pub fn address(self) -> VolAddress<T, R, W> {
+    self.address
+  }
+
+  /// The reset value.
+  #[inline]
+  #[must_use]
+  pub fn reset_value(self) -> T {
+    self.reset
+  }
+}
+
+impl<T, W> VolAddressWithReset<T, Safe, W>
+where
+  T: Copy + PartialEq,
+{
+  /// Volatile reads the address, and checks if it holds the reset value.
+  #[inline]
+  pub fn is_at_reset(self) -> bool {
+    self.address.read() == self.reset
+  }
+}
+impl<T, W> VolAddressWithReset<T, Unsafe, W>
+where
+  T: Copy + PartialEq,
+{
+  /// Volatile reads the address, and checks if it holds the reset value.
+  ///
+  /// ## Safety
+  /// * The safety rules of reading this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn is_at_reset(self) -> bool {
+    self.address.read() == self.reset
+  }
+}
+
+impl<T, R> VolAddressWithReset<T, R, Safe>
+where
+  T: Copy,
+{
+  /// Volatile writes the reset value to the address.
+  #[inline]
+  pub fn reset(self) {
+    self.address.write(self.reset)
+  }
+}
+impl<T, R> VolAddressWithReset<T, R, Unsafe>
+where
+  T: Copy,
+{
+  /// Volatile writes the reset value to the address.
+  ///
+  /// ## Safety
+  /// * The safety rules of writing this address depend on the device. Consult
+  ///   your hardware manual.
+  #[inline]
+  pub unsafe fn reset(self) {
+    self.address.write(self.reset)
+  }
+}
+
+impl<T, R, W> core::fmt::Debug for VolAddressWithReset<T, R, W>
+where
+  T: core::fmt::Debug,
+{
+  #[cold]
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("VolAddressWithReset")
+      .field("address", &self.address)
+      .field("reset", &self.reset)
+      .finish()
+  }
+}
+
+#[test]
+fn test_voladdresswithreset() {
+  let mut hw = 5_u16;
+  let a: VolAddress<u16, Safe, Safe> =
+    unsafe { VolAddress::new(&mut hw as *mut u16 as usize) };
+  let with_reset = VolAddressWithReset::new(a, 0x200);
+  assert!(!with_reset.is_at_reset());
+  with_reset.reset();
+  assert_eq!(a.read(), 0x200);
+  assert!(with_reset.is_at_reset());
+}